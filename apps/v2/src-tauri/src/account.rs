@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::Serialize;
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryContact {
+    pub address: String,
+    pub verified: bool,
+}
+
+/// Verified/pending recovery emails and phone numbers attached to the
+/// account, used for password reset.
+#[derive(Default)]
+pub struct RecoveryContacts {
+    by_address: Mutex<HashMap<String, RecoveryContact>>,
+}
+
+#[tauri::command]
+pub fn get_recovery_contacts(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Vec<RecoveryContact> {
+    state
+        .account
+        .by_address
+        .lock()
+        .unwrap()
+        .values()
+        .cloned()
+        .collect()
+}
+
+/// Starts adding `address` as a recovery contact by recording it as
+/// unverified. No verification code is sent, and nothing in this module
+/// marks an entry verified afterwards.
+#[tauri::command]
+pub fn request_recovery_contact_validation(
+    state: tauri::State<'_, crate::state::AppState>,
+    address: String,
+) -> Result<(), AppError> {
+    if !address.contains('@') && !address.chars().any(|c| c.is_ascii_digit()) {
+        return Err(AppError::Other("not a recognizable email or phone number".into()));
+    }
+    state.account.by_address.lock().unwrap().insert(
+        address.clone(),
+        RecoveryContact {
+            address,
+            verified: false,
+        },
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn remove_recovery_contact(
+    state: tauri::State<'_, crate::state::AppState>,
+    address: String,
+) {
+    state.account.by_address.lock().unwrap().remove(&address);
+}
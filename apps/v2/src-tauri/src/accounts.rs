@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+/// Tracks which of this install's logged-in accounts are suspended — sync
+/// paused and in-memory caches dropped — so a low-memory multi-account
+/// session doesn't pay the cost of keeping every account fully active at
+/// once.
+///
+/// This client's state isn't partitioned per account yet (`AppState` is one
+/// shared struct, not one instance per logged-in user), so suspend/resume
+/// only record intent for now. A future per-account sync engine would
+/// consult this set before spawning or tearing down its loop, and before
+/// evicting that account's in-memory caches; tokens and on-disk stores are
+/// untouched either way, so resuming never requires a fresh login.
+#[derive(Default)]
+pub struct AccountLifecycle {
+    suspended: Mutex<HashSet<String>>,
+}
+
+/// Marks `user_id` suspended: its sync should stop and its in-memory
+/// caches should be released the next time something consults
+/// [`is_account_suspended`].
+#[tauri::command]
+pub fn suspend_account(state: tauri::State<'_, crate::state::AppState>, user_id: String) {
+    state.accounts.suspended.lock().unwrap().insert(user_id);
+}
+
+/// Clears `user_id`'s suspension, so its sync resumes the next time
+/// something consults [`is_account_suspended`].
+#[tauri::command]
+pub fn resume_account(state: tauri::State<'_, crate::state::AppState>, user_id: String) {
+    state.accounts.suspended.lock().unwrap().remove(&user_id);
+}
+
+#[tauri::command]
+pub fn is_account_suspended(state: tauri::State<'_, crate::state::AppState>, user_id: String) -> bool {
+    state.accounts.suspended.lock().unwrap().contains(&user_id)
+}
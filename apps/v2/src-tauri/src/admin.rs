@@ -0,0 +1,17 @@
+//! Synapse admin API client.
+//!
+//! This client does not talk to a Matrix homeserver — there is no
+//! `core/matrix_client` module for a Synapse-specific admin wrapper to sit
+//! next to. Gated behind the `synapse-admin` feature (off by default) so
+//! the request is tracked rather than silently dropped; implement this for
+//! real once/if this app gains a Matrix backend.
+#![cfg(feature = "synapse-admin")]
+
+use crate::error::AppError;
+
+#[tauri::command]
+pub async fn admin_list_users(_homeserver_url: String, _access_token: String) -> Result<(), AppError> {
+    Err(AppError::Other(
+        "synapse admin API is not applicable: this client has no Matrix homeserver backend".into(),
+    ))
+}
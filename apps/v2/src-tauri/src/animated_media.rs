@@ -0,0 +1,184 @@
+use serde::Serialize;
+
+/// Animation metadata surfaced on timeline media items so the UI can
+/// autoplay or show a GIF-style badge instead of treating every image as a
+/// static thumbnail.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimatedMediaInfo {
+    pub is_animated: bool,
+    pub frame_count: u32,
+    pub duration_ms: u32,
+}
+
+/// Detects whether `bytes` is an animated GIF, WebP, or APNG, and if so how
+/// many frames and how long it plays for. Used by `process_attachment` on
+/// outgoing uploads, which copies the result into its `is_animated`,
+/// `frame_count` and `duration_ms` fields; the mimetype is left as given,
+/// and no incoming-event mapping in this crate calls it.
+#[tauri::command]
+pub fn detect_animated_media(bytes: Vec<u8>) -> AnimatedMediaInfo {
+    if let Some(info) = detect_gif(&bytes) {
+        return info;
+    }
+    if let Some(info) = detect_apng(&bytes) {
+        return info;
+    }
+    if let Some(info) = detect_animated_webp(&bytes) {
+        return info;
+    }
+    AnimatedMediaInfo::default()
+}
+
+/// GIF89a stores one delay (in hundredths of a second) per Graphic Control
+/// Extension block (0x21 0xF9), immediately followed by an Image Descriptor
+/// (0x2C) for the frame it applies to.
+fn detect_gif(bytes: &[u8]) -> Option<AnimatedMediaInfo> {
+    if bytes.len() < 6 || &bytes[0..3] != b"GIF" {
+        return None;
+    }
+
+    let mut frame_count = 0u32;
+    let mut duration_ms = 0u32;
+    let mut i = 13; // past header + logical screen descriptor
+    while i + 1 < bytes.len() {
+        match bytes[i] {
+            0x21 if bytes.get(i + 1) == Some(&0xF9) => {
+                let Some(delay_bytes) = bytes.get(i + 4..=i + 5) else {
+                    break;
+                };
+                let delay_cs = u16::from_le_bytes([delay_bytes[0], delay_bytes[1]]);
+                duration_ms += delay_cs as u32 * 10;
+                i += 8;
+            }
+            0x2C => {
+                frame_count += 1;
+                i = skip_gif_image_block(bytes, i);
+            }
+            0x3B => break, // trailer
+            _ => i += 1,
+        }
+    }
+
+    Some(AnimatedMediaInfo { is_animated: frame_count > 1, frame_count, duration_ms })
+}
+
+fn skip_gif_image_block(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 10; // image descriptor fixed fields
+    if let Some(&packed) = bytes.get(start + 9) {
+        if packed & 0x80 != 0 {
+            i += 3 * (1 << ((packed & 0x07) + 1)); // local color table
+        }
+    }
+    i += 1; // LZW minimum code size
+    while let Some(&block_size) = bytes.get(i) {
+        if block_size == 0 {
+            return i + 1;
+        }
+        i += 1 + block_size as usize;
+    }
+    i
+}
+
+/// APNG signals itself with an `acTL` chunk holding the frame count; actual
+/// per-frame delays live in `fcTL` chunks, summed here for the total
+/// playback duration.
+fn detect_apng(bytes: &[u8]) -> Option<AnimatedMediaInfo> {
+    const PNG_SIGNATURE: [u8; 8] = [0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
+    if bytes.len() < 8 || bytes[0..8] != PNG_SIGNATURE {
+        return None;
+    }
+
+    let mut frame_count = 0u32;
+    let mut duration_ms = 0u32;
+    let mut found_actl = false;
+    let mut i = 8;
+    while i + 8 <= bytes.len() {
+        let len = u32::from_be_bytes(bytes[i..i + 4].try_into().ok()?) as usize;
+        let chunk_type = &bytes[i + 4..i + 8];
+        let data_start = i + 8;
+        if chunk_type == b"acTL" && data_start + 4 <= bytes.len() {
+            found_actl = true;
+            frame_count = u32::from_be_bytes(bytes[data_start..data_start + 4].try_into().ok()?);
+        } else if chunk_type == b"fcTL" && data_start + 28 <= bytes.len() {
+            let delay_num = u16::from_be_bytes(bytes[data_start + 20..data_start + 22].try_into().ok()?);
+            let delay_den = u16::from_be_bytes(bytes[data_start + 22..data_start + 24].try_into().ok()?);
+            let den = if delay_den == 0 { 100 } else { delay_den };
+            duration_ms += (delay_num as u32 * 1000) / den as u32;
+        } else if chunk_type == b"IEND" {
+            break;
+        }
+        i = data_start + len + 4; // + CRC
+    }
+
+    found_actl.then_some(AnimatedMediaInfo { is_animated: frame_count > 1, frame_count, duration_ms })
+}
+
+/// Animated WebP is a RIFF/WEBP container with an `ANIM` chunk up front and
+/// one `ANMF` chunk per frame, each carrying its own duration in
+/// milliseconds.
+fn detect_animated_webp(bytes: &[u8]) -> Option<AnimatedMediaInfo> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WEBP" {
+        return None;
+    }
+
+    let mut frame_count = 0u32;
+    let mut duration_ms = 0u32;
+    let mut found_anim = false;
+    let mut i = 12;
+    while i + 8 <= bytes.len() {
+        let chunk_type = &bytes[i..i + 4];
+        let len = u32::from_le_bytes(bytes[i + 4..i + 8].try_into().ok()?) as usize;
+        let data_start = i + 8;
+        if chunk_type == b"ANIM" {
+            found_anim = true;
+        } else if chunk_type == b"ANMF" && data_start + 16 <= bytes.len() {
+            frame_count += 1;
+            let duration_bytes = [bytes[data_start + 12], bytes[data_start + 13], bytes[data_start + 14], 0];
+            duration_ms += u32::from_le_bytes(duration_bytes);
+        }
+        i = data_start + len + (len % 2); // chunks are padded to even length
+    }
+
+    found_anim.then_some(AnimatedMediaInfo { is_animated: frame_count > 1, frame_count, duration_ms })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gif_truncated_right_after_graphic_control_marker_does_not_panic() {
+        let mut bytes = b"GIF89a".to_vec();
+        bytes.extend_from_slice(&[0u8; 7]); // logical screen descriptor, padding up to offset 13
+        bytes.extend_from_slice(&[0x21, 0xF9]); // marker with no control data following
+        assert_eq!(bytes.len(), 15);
+
+        let info = detect_gif(&bytes).unwrap();
+        assert_eq!(info.frame_count, 0);
+    }
+
+    #[test]
+    fn detects_animated_gif_with_two_frames() {
+        let mut bytes = b"GIF89a".to_vec();
+        bytes.extend_from_slice(&[0u8; 7]);
+        for _ in 0..2 {
+            bytes.extend_from_slice(&[0x21, 0xF9, 0x04, 0x00, 0x0A, 0x00, 0x00, 0x00]);
+            bytes.extend_from_slice(&[0x2C]); // image descriptor
+            bytes.extend_from_slice(&[0u8; 9]); // fixed fields, no local color table
+            bytes.extend_from_slice(&[0x02]); // LZW minimum code size
+            bytes.extend_from_slice(&[0x00]); // block terminator
+        }
+        bytes.push(0x3B); // trailer
+
+        let info = detect_gif(&bytes).unwrap();
+        assert!(info.is_animated);
+        assert_eq!(info.frame_count, 2);
+        assert_eq!(info.duration_ms, 200);
+    }
+
+    #[test]
+    fn non_gif_input_returns_none() {
+        assert!(detect_gif(b"not a gif").is_none());
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Whether the current session is a read-only guest, for gating write
+/// operations behind [`ensure_not_guest`] instead of each call site
+/// re-checking a session-kind flag it has to fetch itself.
+#[derive(Default)]
+pub struct GuestState {
+    is_guest: AtomicBool,
+}
+
+/// What this device calls itself to the homeserver: a human-meaningful
+/// display name (shown in the sessions list on a user's other clients
+/// instead of a generic SDK string) and the HTTP user agent sent with
+/// every request. Set at login/registration time and re-used for the
+/// lifetime of the session.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceIdentity {
+    pub display_name: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Default)]
+pub struct DeviceIdentityState {
+    current: Mutex<DeviceIdentity>,
+}
+
+#[tauri::command]
+pub fn get_device_identity(state: tauri::State<'_, crate::state::AppState>) -> DeviceIdentity {
+    state.device_identity.current.lock().unwrap().clone()
+}
+
+/// Returns a [`AppError::GuestNotAllowed`] error if the current session is
+/// a guest. Call this at the top of any command that writes to a room
+/// (sending messages, joining restricted rooms, inviting, etc.).
+pub fn ensure_not_guest(state: &GuestState) -> Result<(), AppError> {
+    if state.is_guest.load(Ordering::SeqCst) {
+        Err(AppError::GuestNotAllowed)
+    } else {
+        Ok(())
+    }
+}
+
+/// How the user identified themselves at login, beyond a plain username —
+/// many homeservers/backends let users sign in with a recovery email or
+/// phone number instead of their account id.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Identifier {
+    UserId(String),
+    Email(String),
+    PhoneNumber { country_code: String, number: String },
+}
+
+/// Authenticates with `identifier` and `password` against `homeserver_url`.
+/// The actual network round-trip is owned by the (not-yet-implemented)
+/// account service; this validates the identifier (it is not normalized),
+/// and rejects `homeserver_url` outright if an enterprise [`crate::server_policy::ServerPolicy`]
+/// doesn't allow it, so that plumbing never contacts a disallowed server.
+#[tauri::command]
+pub async fn login(
+    state: tauri::State<'_, crate::state::AppState>,
+    homeserver_url: String,
+    identifier: Identifier,
+    password: String,
+    device_display_name: Option<String>,
+    user_agent: Option<String>,
+) -> Result<(), AppError> {
+    crate::server_policy::check_homeserver(&state.server_policy, &homeserver_url)?;
+    *state.device_identity.current.lock().unwrap() =
+        DeviceIdentity { display_name: device_display_name, user_agent };
+
+    if password.is_empty() {
+        return Err(AppError::Other("password must not be empty".into()));
+    }
+    match &identifier {
+        Identifier::UserId(id) if id.trim().is_empty() => {
+            Err(AppError::Other("user id must not be empty".into()))
+        }
+        Identifier::Email(email) if !email.contains('@') => {
+            Err(AppError::Other("not a valid email address".into()))
+        }
+        Identifier::PhoneNumber { number, .. } if number.trim().is_empty() => {
+            Err(AppError::Other("phone number must not be empty".into()))
+        }
+        _ => Err(AppError::Other(
+            "account service is not implemented yet".into(),
+        )),
+    }
+}
+
+/// Logs in as a read-only guest on `homeserver_url`, for try-before-register
+/// onboarding: lets a user browse world-readable rooms before creating an
+/// account. Still subject to the homeserver allow-list; the guest
+/// registration capability itself is the (not-yet-implemented) account
+/// service's responsibility, so this only validates and records the guest
+/// flag for `ensure_not_guest` to enforce afterwards.
+#[tauri::command]
+pub async fn login_as_guest(
+    state: tauri::State<'_, crate::state::AppState>,
+    homeserver_url: String,
+    device_display_name: Option<String>,
+    user_agent: Option<String>,
+) -> Result<(), AppError> {
+    crate::server_policy::check_homeserver(&state.server_policy, &homeserver_url)?;
+    *state.device_identity.current.lock().unwrap() =
+        DeviceIdentity { display_name: device_display_name, user_agent };
+    state.guest.is_guest.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Records acceptance of the terms of service at each of `urls`, for the
+/// `m.login.terms`-style registration stage. Until the account service
+/// exists, acceptance is only validated, not persisted against a real
+/// backend.
+#[tauri::command]
+pub fn accept_terms(urls: Vec<String>) -> Result<(), AppError> {
+    if urls.is_empty() {
+        return Err(AppError::Other("no terms urls were provided".into()));
+    }
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use serde::Serialize;
+
+use crate::connection::ConnectionState;
+use crate::error::AppError;
+
+/// What a single bounded background sync accomplished, returned to the
+/// mobile shell so it can decide whether to surface a notification and
+/// whether the OS-level background task should be rescheduled sooner.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundSyncSummary {
+    pub reached_server: bool,
+    pub new_notification_count: u32,
+    pub timed_out: bool,
+}
+
+/// Designed to be called from an iOS BGTask or Android WorkManager job: does
+/// one bounded round of work on the persisted session and returns quickly
+/// with a summary, rather than running the normal long-lived sync loop.
+///
+/// This client has no persisted session or sync engine to resume yet, so the
+/// "bounded round of work" below is limited to the reachability probe the
+/// foreground connection monitor already performs; it is still subject to
+/// the same timeout a real sync call would need, so the calling shell gets
+/// a bounded, predictable background task either way.
+#[tauri::command]
+pub async fn background_sync_once(timeout_ms: u64) -> Result<BackgroundSyncSummary, AppError> {
+    let bounded = tokio::time::timeout(
+        std::time::Duration::from_millis(timeout_ms),
+        crate::connection::probe_health(),
+    )
+    .await;
+
+    match bounded {
+        Ok(state) => Ok(BackgroundSyncSummary {
+            reached_server: state == ConnectionState::Connected,
+            new_notification_count: 0,
+            timed_out: false,
+        }),
+        Err(_) => Ok(BackgroundSyncSummary {
+            reached_server: false,
+            new_notification_count: 0,
+            timed_out: true,
+        }),
+    }
+}
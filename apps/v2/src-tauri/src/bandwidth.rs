@@ -0,0 +1,37 @@
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BandwidthMode {
+    #[default]
+    Normal,
+    Low,
+}
+
+#[derive(Default)]
+pub struct BandwidthState {
+    mode: Mutex<BandwidthMode>,
+}
+
+impl BandwidthState {
+    pub fn mode(&self) -> BandwidthMode {
+        *self.mode.lock().unwrap()
+    }
+}
+
+/// Switches the global bandwidth mode. In `Low`, callers should disable URL
+/// previews, shrink sync timeline-limit filters, defer media/avatar
+/// downloads until explicitly requested, and use a longer sync long-poll
+/// timeout — all gated on `get_bandwidth_mode()` rather than duplicated
+/// network-type checks scattered through the app.
+#[tauri::command]
+pub fn set_bandwidth_mode(state: tauri::State<'_, crate::state::AppState>, mode: BandwidthMode) {
+    *state.bandwidth.mode.lock().unwrap() = mode;
+}
+
+#[tauri::command]
+pub fn get_bandwidth_mode(state: tauri::State<'_, crate::state::AppState>) -> BandwidthMode {
+    state.bandwidth.mode()
+}
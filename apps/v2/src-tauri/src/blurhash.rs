@@ -0,0 +1,241 @@
+//! Pure-Rust BlurHash encode/decode (https://blurha.sh), used to give
+//! outgoing images a compact placeholder that can render before the real
+//! download finishes.
+
+use std::f64::consts::PI;
+
+use crate::error::AppError;
+
+const BASE83_CHARS: &[u8] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/// Computes the BlurHash for an already-decoded RGBA8 image. `components_x`
+/// and `components_y` control fidelity (1-9 each); 4x3 is a good default
+/// for thumbnails.
+pub fn encode(
+    rgba: &[u8],
+    width: usize,
+    height: usize,
+    components_x: usize,
+    components_y: usize,
+) -> Result<String, AppError> {
+    if !(1..=9).contains(&components_x) || !(1..=9).contains(&components_y) {
+        return Err(AppError::Other("components must be in 1..=9".into()));
+    }
+    if rgba.len() != width * height * 4 {
+        return Err(AppError::Other("pixel buffer does not match width*height*4".into()));
+    }
+
+    let mut factors = Vec::with_capacity(components_x * components_y);
+    for j in 0..components_y {
+        for i in 0..components_x {
+            let normalization = if i == 0 && j == 0 { 1.0 } else { 2.0 };
+            factors.push(multiply_basis_function(rgba, width, height, i, j, normalization));
+        }
+    }
+
+    let dc = factors[0];
+    let ac = &factors[1..];
+
+    let mut hash = String::new();
+    hash.push_str(&base83_encode((components_x - 1 + (components_y - 1) * 9) as u32, 1));
+
+    let maximum_value = if ac.is_empty() {
+        1.0
+    } else {
+        let actual_max = ac.iter().map(|(r, g, b)| r.abs().max(g.abs()).max(b.abs())).fold(0.0_f64, f64::max);
+        ((actual_max * 166.0 - 0.5).floor().max(0.0).min(82.0) + 1.0) / 166.0
+    };
+    let quantized_max = ((maximum_value * 166.0 - 1.0).round() as u32).min(82);
+    hash.push_str(&base83_encode(quantized_max, 1));
+
+    hash.push_str(&base83_encode(encode_dc(dc), 4));
+    for &c in ac {
+        hash.push_str(&base83_encode(encode_ac(c, maximum_value), 2));
+    }
+
+    Ok(hash)
+}
+
+/// Decodes a BlurHash string back into an RGBA8 buffer of `width` x `height`.
+pub fn decode(hash: &str, width: usize, height: usize, punch: f64) -> Result<Vec<u8>, AppError> {
+    if !hash.is_ascii() {
+        return Err(AppError::Other("invalid blurhash character".into()));
+    }
+    if hash.len() < 6 {
+        return Err(AppError::Other("blurhash too short".into()));
+    }
+    let size_flag = base83_decode(&hash[0..1])?;
+    let components_x = (size_flag % 9) as usize + 1;
+    let components_y = (size_flag / 9) as usize + 1;
+
+    let expected_len = 4 + 2 * components_x * components_y;
+    if hash.len() != expected_len {
+        return Err(AppError::Other("blurhash length does not match component count".into()));
+    }
+
+    let quantized_max = base83_decode(&hash[1..2])?;
+    let maximum_value = (quantized_max as f64 + 1.0) / 166.0;
+
+    let mut colors = Vec::with_capacity(components_x * components_y);
+    colors.push(decode_dc(base83_decode(&hash[2..6])?));
+    for i in 0..(components_x * components_y - 1) {
+        let start = 6 + i * 2;
+        let value = base83_decode(&hash[start..start + 2])?;
+        colors.push(decode_ac(value, maximum_value * punch));
+    }
+
+    let mut pixels = vec![0u8; width * height * 4];
+    for y in 0..height {
+        for x in 0..width {
+            let mut r = 0.0;
+            let mut g = 0.0;
+            let mut b = 0.0;
+            for j in 0..components_y {
+                for i in 0..components_x {
+                    let basis = (PI * x as f64 * i as f64 / width as f64).cos()
+                        * (PI * y as f64 * j as f64 / height as f64).cos();
+                    let (cr, cg, cb) = colors[i + j * components_x];
+                    r += cr * basis;
+                    g += cg * basis;
+                    b += cb * basis;
+                }
+            }
+            let idx = (y * width + x) * 4;
+            pixels[idx] = linear_to_srgb(r);
+            pixels[idx + 1] = linear_to_srgb(g);
+            pixels[idx + 2] = linear_to_srgb(b);
+            pixels[idx + 3] = 255;
+        }
+    }
+
+    Ok(pixels)
+}
+
+fn multiply_basis_function(
+    rgba: &[u8],
+    width: usize,
+    height: usize,
+    i: usize,
+    j: usize,
+    normalization: f64,
+) -> (f64, f64, f64) {
+    let mut r = 0.0;
+    let mut g = 0.0;
+    let mut b = 0.0;
+    for y in 0..height {
+        for x in 0..width {
+            let basis = (PI * i as f64 * x as f64 / width as f64).cos()
+                * (PI * j as f64 * y as f64 / height as f64).cos();
+            let idx = (y * width + x) * 4;
+            r += basis * srgb_to_linear(rgba[idx]);
+            g += basis * srgb_to_linear(rgba[idx + 1]);
+            b += basis * srgb_to_linear(rgba[idx + 2]);
+        }
+    }
+    let scale = normalization / (width * height) as f64;
+    (r * scale, g * scale, b * scale)
+}
+
+fn srgb_to_linear(value: u8) -> f64 {
+    let v = value as f64 / 255.0;
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f64) -> u8 {
+    let v = value.clamp(0.0, 1.0);
+    let srgb = if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (srgb * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+fn sign_pow(value: f64, exp: f64) -> f64 {
+    value.abs().powf(exp).copysign(value)
+}
+
+fn encode_dc(color: (f64, f64, f64)) -> u32 {
+    let (r, g, b) = color;
+    (linear_to_srgb(r) as u32) << 16 | (linear_to_srgb(g) as u32) << 8 | linear_to_srgb(b) as u32
+}
+
+fn decode_dc(value: u32) -> (f64, f64, f64) {
+    (
+        srgb_to_linear(((value >> 16) & 0xff) as u8),
+        srgb_to_linear(((value >> 8) & 0xff) as u8),
+        srgb_to_linear((value & 0xff) as u8),
+    )
+}
+
+fn encode_ac(color: (f64, f64, f64), maximum_value: f64) -> u32 {
+    let quantize = |c: f64| {
+        (sign_pow(c / maximum_value, 0.5) * 9.0 + 9.5).floor().clamp(0.0, 18.0) as u32
+    };
+    quantize(color.0) * 19 * 19 + quantize(color.1) * 19 + quantize(color.2)
+}
+
+fn decode_ac(value: u32, maximum_value: f64) -> (f64, f64, f64) {
+    let r = (value / (19 * 19)) % 19;
+    let g = (value / 19) % 19;
+    let b = value % 19;
+    let unquantize = |c: u32| sign_pow((c as f64 - 9.0) / 9.0, 2.0) * maximum_value;
+    (unquantize(r), unquantize(g), unquantize(b))
+}
+
+fn base83_encode(value: u32, length: usize) -> String {
+    let mut remaining = value;
+    let mut digits = vec![0u8; length];
+    for slot in digits.iter_mut().rev() {
+        *slot = BASE83_CHARS[(remaining % 83) as usize];
+        remaining /= 83;
+    }
+    String::from_utf8(digits).unwrap()
+}
+
+fn base83_decode(s: &str) -> Result<u32, AppError> {
+    let mut value = 0u32;
+    for ch in s.bytes() {
+        let digit = BASE83_CHARS
+            .iter()
+            .position(|&c| c == ch)
+            .ok_or_else(|| AppError::Other("invalid blurhash character".into()))?;
+        value = value * 83 + digit as u32;
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_then_decode_round_trips_without_panicking() {
+        let width = 4;
+        let height = 4;
+        let rgba = vec![128u8; width * height * 4];
+        let hash = encode(&rgba, width, height, 4, 3).unwrap();
+        let pixels = decode(&hash, width, height, 1.0).unwrap();
+        assert_eq!(pixels.len(), width * height * 4);
+    }
+
+    #[test]
+    fn decode_rejects_non_ascii_instead_of_panicking() {
+        // Right byte length (6) but contains a multi-byte UTF-8 character,
+        // which must not be sliced into before the ASCII check runs.
+        let hash = "\u{00e9}AAAA";
+        assert_eq!(hash.len(), 6);
+        let result = decode(hash, 1, 1, 1.0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decode_rejects_too_short_hash() {
+        assert!(decode("AA", 1, 1, 1.0).is_err());
+    }
+}
@@ -0,0 +1,28 @@
+//! Detection of bridged rooms (`m.bridge` state) and tagging of
+//! bridge-ghost senders, so the UI can render protocol badges.
+//!
+//! This client has no room-state ingestion yet — nothing reads `m.bridge`
+//! or any other state event off a synced room, so there is nothing for
+//! [`get_bridge_info`] to inspect. Recording the request here rather than
+//! dropping it; a real implementation needs state-event ingestion wired up
+//! first, and the result would hang off a `RoomInfo` type that doesn't
+//! exist yet either.
+use crate::error::AppError;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeInfo {
+    pub protocol: String,
+    pub network_name: String,
+    pub external_channel_id: String,
+}
+
+#[tauri::command]
+pub fn get_bridge_info(_room_id: String) -> Result<Option<BridgeInfo>, AppError> {
+    Err(AppError::Other("not applicable: this client has no room-state ingestion".into()))
+}
+
+#[tauri::command]
+pub fn is_bridge_ghost(_user_id: String, _room_id: String) -> Result<bool, AppError> {
+    Err(AppError::Other("not applicable: this client has no room-state ingestion".into()))
+}
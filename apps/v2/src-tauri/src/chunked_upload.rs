@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::error::AppError;
+use crate::send_queue::PendingSend;
+
+const CHUNK_SIZE: u64 = 5 * 1024 * 1024;
+const MAX_RESTART_ATTEMPTS: u32 = 3;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadProgress {
+    pub uploaded_bytes: u64,
+    pub total_bytes: u64,
+    pub complete: bool,
+}
+
+/// Uploads `path` to `upload_url` in `CHUNK_SIZE` chunks, resuming from
+/// `send.uploaded_offset` rather than restarting from zero, so a flaky
+/// connection only costs the current chunk instead of the whole file.
+///
+/// Each chunk is sent with a `Content-Range` header and, when the request
+/// fails or the server answers with a non-success status, retried up to
+/// `MAX_RESTART_ATTEMPTS` times with exponential backoff before the whole
+/// upload fails. The server is never asked which ranges it already holds;
+/// the resume point comes only from `send.uploaded_offset`. Progress is
+/// written back to the send queue after every chunk so a process death
+/// loses at most one chunk.
+#[tauri::command]
+pub async fn upload_chunked(
+    state: tauri::State<'_, crate::state::AppState>,
+    upload_url: String,
+    path: PathBuf,
+    mut send: PendingSend,
+) -> Result<UploadProgress, AppError> {
+    let bytes = tokio::fs::read(&path).await?;
+    let total_bytes = bytes.len() as u64;
+
+    let client = reqwest::Client::new();
+    let mut offset = send.uploaded_offset.min(total_bytes);
+
+    while offset < total_bytes {
+        let end = next_chunk_end(offset, total_bytes);
+        let chunk = bytes[offset as usize..end as usize].to_vec();
+
+        send_chunk_with_backoff(&client, &upload_url, chunk, offset, total_bytes).await?;
+
+        offset = end;
+        send.uploaded_offset = offset;
+        crate::send_queue::update_pending_send_offset(
+            state.clone(),
+            send.local_id.clone(),
+            offset,
+        )?;
+    }
+
+    Ok(UploadProgress { uploaded_bytes: offset, total_bytes, complete: true })
+}
+
+/// The end offset (exclusive) of the next chunk starting at `offset`, capped
+/// at `total_bytes` so the final chunk isn't padded past the file's end.
+fn next_chunk_end(offset: u64, total_bytes: u64) -> u64 {
+    (offset + CHUNK_SIZE).min(total_bytes)
+}
+
+fn format_content_range(offset: u64, chunk_len: u64, total_bytes: u64) -> String {
+    let range_end = offset + chunk_len - 1;
+    format!("bytes {offset}-{range_end}/{total_bytes}")
+}
+
+async fn send_chunk_with_backoff(
+    client: &reqwest::Client,
+    upload_url: &str,
+    chunk: Vec<u8>,
+    offset: u64,
+    total_bytes: u64,
+) -> Result<(), AppError> {
+    let content_range = format_content_range(offset, chunk.len() as u64, total_bytes);
+
+    let mut last_error = None;
+    for attempt in 0..MAX_RESTART_ATTEMPTS {
+        let result = client
+            .put(upload_url)
+            .header("Content-Range", content_range.clone())
+            .body(chunk.clone())
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(response) => last_error = Some(format!("server returned {}", response.status())),
+            Err(err) => last_error = Some(err.to_string()),
+        }
+
+        tokio::time::sleep(std::time::Duration::from_secs(1 << attempt)).await;
+    }
+
+    Err(AppError::Other(format!(
+        "chunk at offset {offset} failed after {MAX_RESTART_ATTEMPTS} attempts: {}",
+        last_error.unwrap_or_default()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_chunk_end_stays_within_chunk_size() {
+        assert_eq!(next_chunk_end(0, 100 * 1024 * 1024), CHUNK_SIZE);
+    }
+
+    #[test]
+    fn next_chunk_end_caps_at_total_bytes_for_the_last_chunk() {
+        let total = CHUNK_SIZE + 10;
+        assert_eq!(next_chunk_end(CHUNK_SIZE, total), total);
+    }
+
+    #[test]
+    fn format_content_range_matches_http_range_syntax() {
+        assert_eq!(format_content_range(0, 100, 1000), "bytes 0-99/1000");
+        assert_eq!(format_content_range(100, 50, 1000), "bytes 100-149/1000");
+    }
+}
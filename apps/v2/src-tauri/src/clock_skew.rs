@@ -0,0 +1,63 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use chrono::{DateTime, Utc};
+
+use crate::error::AppError;
+
+/// Measured offset between the local clock and the homeserver's clock, in
+/// milliseconds (`server_time - local_time`), applied whenever Rust computes
+/// a "now" used for something the server also has an opinion about — relative
+/// timestamps, self-destruct expiry, scheduled-send due times — so a local
+/// clock that's drifted doesn't fire those early/late relative to the server.
+/// There's no background probe here: nothing in this tree yet makes periodic
+/// requests to a homeserver, so callers that do (a `/versions` fetch, a sync
+/// response, anything with a `Date` header) report the sample via
+/// [`record_clock_skew_sample`] instead of this module inventing its own.
+#[derive(Default)]
+pub struct ClockSkewState {
+    offset_ms: AtomicI64,
+}
+
+/// Records a fresh measurement: `server_time_rfc3339` is the server's
+/// reported time for roughly "now" (e.g. a response's `Date` header, or a
+/// homeserver API's own timestamp field), compared against the local clock
+/// at the moment the sample was taken.
+#[tauri::command]
+pub fn record_clock_skew_sample(
+    state: tauri::State<'_, crate::state::AppState>,
+    server_time_rfc3339: String,
+) -> Result<(), AppError> {
+    let server_time = DateTime::parse_from_rfc3339(&server_time_rfc3339)
+        .map_err(|e| AppError::Other(e.to_string()))?
+        .with_timezone(&Utc);
+    let offset_ms = server_time.signed_duration_since(Utc::now()).num_milliseconds();
+    state.clock_skew.offset_ms.store(offset_ms, Ordering::Relaxed);
+    Ok(())
+}
+
+/// The last measured offset in milliseconds, exposed so the frontend can
+/// apply the same correction to "now" labels it renders itself instead of
+/// trusting local time alone.
+#[tauri::command]
+pub fn get_clock_skew_offset_ms(state: tauri::State<'_, crate::state::AppState>) -> i64 {
+    state.clock_skew.offset_ms.load(Ordering::Relaxed)
+}
+
+/// Whether the measured offset is large enough to matter. Below this, clock
+/// drift is in the noise of network latency itself and correcting for it
+/// would just add jitter instead of removing it.
+const SIGNIFICANT_SKEW_MS: i64 = 5_000;
+
+#[tauri::command]
+pub fn is_clock_skew_significant(state: tauri::State<'_, crate::state::AppState>) -> bool {
+    state.clock_skew.offset_ms.load(Ordering::Relaxed).abs() >= SIGNIFICANT_SKEW_MS
+}
+
+/// The local clock's idea of "now", corrected by the last measured skew.
+/// Self-destruct expiry and scheduled-send due checks should compare
+/// against this rather than a bare `Utc::now()`, since both are deadlines
+/// set relative to the server's clock, not the device's.
+pub(crate) fn corrected_now(state: &crate::state::AppState) -> DateTime<Utc> {
+    let offset_ms = state.clock_skew.offset_ms.load(Ordering::Relaxed);
+    Utc::now() + chrono::Duration::milliseconds(offset_ms)
+}
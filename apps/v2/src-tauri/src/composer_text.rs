@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+/// Hand-rolled shortcode table rather than pulling in an emoji data crate
+/// for what's currently a handful of common shortcuts — the same tradeoff
+/// [`crate::server_acl`]'s glob matcher makes for its one call site.
+const EMOJI_SHORTCODES: &[(&str, &str)] = &[
+    ("smile", "🙂"),
+    ("grin", "😁"),
+    ("laughing", "😂"),
+    ("wink", "😉"),
+    ("heart", "❤️"),
+    ("thumbsup", "👍"),
+    ("thumbsdown", "👎"),
+    ("fire", "🔥"),
+    ("tada", "🎉"),
+    ("eyes", "👀"),
+    ("thinking", "🤔"),
+    ("wave", "👋"),
+    ("clap", "👏"),
+    ("rocket", "🚀"),
+    ("cry", "😢"),
+];
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AutocompleteKind {
+    Mention,
+    Emoji,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutocompleteCandidate {
+    pub kind: AutocompleteKind,
+    /// What typing this candidate should replace the trigger token with.
+    pub replacement: String,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposerAutocomplete {
+    /// Byte offset `text` should be spliced at when a candidate is chosen —
+    /// everything from here to `cursor` is the trigger token being replaced.
+    pub trigger_start: usize,
+    pub candidates: Vec<AutocompleteCandidate>,
+}
+
+/// Finds the `@mention` or `:emoji` token immediately left of `cursor` (if
+/// any — a token ends at the nearest preceding whitespace), returning its
+/// start offset and text. `cursor` arrives from the frontend as an editor
+/// cursor position, not a guaranteed Rust char boundary, so it's floored to
+/// the nearest valid one before any slicing — otherwise a cursor landing
+/// inside a multi-byte character (e.g. just past an emoji) panics.
+fn find_trigger_token(text: &str, cursor: usize) -> (usize, &str) {
+    let cursor = crate::event_size_guard::floor_char_boundary(text, cursor);
+    let prefix = &text[..cursor];
+    let trigger_start = prefix.rfind(char::is_whitespace).map(|i| i + 1).unwrap_or(0);
+    (trigger_start, &prefix[trigger_start..])
+}
+
+/// Finds the `@mention` or `:emoji` token immediately left of `cursor` (if
+/// any — a token ends at the nearest preceding whitespace) and returns
+/// matching candidates, so the composer can drive an autocomplete popup the
+/// same way on every platform instead of each one re-implementing the
+/// trigger-detection rules.
+#[tauri::command]
+pub fn process_composer_text(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    text: String,
+    cursor: usize,
+) -> ComposerAutocomplete {
+    let (trigger_start, token) = find_trigger_token(&text, cursor);
+
+    let candidates = if let Some(query) = token.strip_prefix('@') {
+        crate::members::search_room_members(state, room_id, query.to_string())
+            .into_iter()
+            .map(|member| AutocompleteCandidate {
+                kind: AutocompleteKind::Mention,
+                replacement: format!("@{}", member.display_name),
+                label: format!("{} ({})", member.display_name, member.user_id),
+            })
+            .collect()
+    } else if let Some(query) = token.strip_prefix(':') {
+        if query.is_empty() {
+            Vec::new()
+        } else {
+            EMOJI_SHORTCODES
+                .iter()
+                .filter(|(code, _)| code.starts_with(query))
+                .map(|(code, emoji)| AutocompleteCandidate {
+                    kind: AutocompleteKind::Emoji,
+                    replacement: format!(":{code}:"),
+                    label: format!("{emoji} :{code}:"),
+                })
+                .collect()
+        }
+    } else {
+        Vec::new()
+    };
+
+    ComposerAutocomplete { trigger_start, candidates }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedMention {
+    pub display_name: String,
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposerRenderResult {
+    pub plain: String,
+    pub html: String,
+}
+
+/// Final send-time transform: expands `:shortcode:` into its emoji and each
+/// resolved `@mention` into an `<a>` pill linking to this app's own
+/// permalink scheme (see [`crate::permalink`]), producing both the plain
+/// body and the formatted HTML body so every platform sends an identical
+/// `m.text`/`formatted_body` pair instead of re-deriving one from the other
+/// client-side.
+#[tauri::command]
+pub fn render_composer_text(text: String, mentions: Vec<ResolvedMention>) -> ComposerRenderResult {
+    let mut plain = text.clone();
+    let mut html = html_escape(&text);
+
+    for (code, emoji) in EMOJI_SHORTCODES {
+        let token = format!(":{code}:");
+        plain = plain.replace(&token, emoji);
+        html = html.replace(&token, emoji);
+    }
+
+    for mention in &mentions {
+        let token = format!("@{}", mention.display_name);
+        let pill = format!(
+            "<a href=\"skiffy://user/{}\">{}</a>",
+            html_escape(&mention.user_id),
+            html_escape(&mention.display_name)
+        );
+        html = html.replace(&html_escape(&token), &pill);
+    }
+
+    ComposerRenderResult { plain, html }
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_trigger_token_clamps_cursor_inside_multi_byte_char_instead_of_panicking() {
+        let text = "hi \u{1F600} there";
+        // Byte 5 lands inside the emoji's 4-byte UTF-8 encoding.
+        let (_, token) = find_trigger_token(text, 5);
+        assert_eq!(token, "");
+    }
+
+    #[test]
+    fn find_trigger_token_finds_mention_prefix() {
+        let (start, token) = find_trigger_token("hello @ali", 10);
+        assert_eq!(start, 6);
+        assert_eq!(token, "@ali");
+    }
+
+    #[test]
+    fn find_trigger_token_finds_emoji_prefix() {
+        let (start, token) = find_trigger_token("nice :fir", 9);
+        assert_eq!(start, 5);
+        assert_eq!(token, ":fir");
+    }
+
+    #[test]
+    fn render_composer_text_expands_shortcode_and_mention() {
+        let result = render_composer_text(
+            "hi :fire: @Alice".to_string(),
+            vec![ResolvedMention { display_name: "Alice".to_string(), user_id: "@alice:example.org".to_string() }],
+        );
+        assert_eq!(result.plain, "hi 🔥 @Alice");
+        assert!(result.html.contains("skiffy://user/@alice:example.org"));
+        assert!(result.html.contains("🔥"));
+    }
+}
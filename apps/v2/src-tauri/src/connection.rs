@@ -0,0 +1,67 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+/// Event emitted on the `AppHandle` whenever the derived connection state changes.
+pub const CONNECTION_STATE_CHANGED: &str = "connection://state-changed";
+
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionState {
+    #[default]
+    Connecting,
+    Connected,
+    Offline,
+    ServerUnreachable,
+}
+
+#[derive(Default)]
+pub struct ConnectionMonitor {
+    state: Mutex<ConnectionState>,
+}
+
+/// Returns the last known connection state without blocking on a fresh probe;
+/// callers should pair this with the `connection://state-changed` event for
+/// live updates rather than polling.
+#[tauri::command]
+pub fn get_connection_state(state: tauri::State<'_, crate::state::AppState>) -> ConnectionState {
+    *state.connection.state.lock().unwrap()
+}
+
+/// Starts the background health-check loop. Called once from `run()`'s setup
+/// hook; do not call per-window, or duplicate loops will race on the same state.
+pub fn spawn_monitor(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let observed = probe_health().await;
+            let changed = {
+                let state = app.state::<crate::state::AppState>();
+                let mut guard = state.connection.state.lock().unwrap();
+                if *guard == observed {
+                    false
+                } else {
+                    *guard = observed;
+                    true
+                }
+            };
+            if changed {
+                crate::streams::coalesced_emit(&app, CONNECTION_STATE_CHANGED, observed);
+            }
+            tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+        }
+    });
+}
+
+/// Lightweight reachability probe. This stands in for the eventual
+/// sync-loop-error-derived check; for now it only distinguishes "online"
+/// from "offline" so the UI banner has something real to react to.
+pub(crate) async fn probe_health() -> ConnectionState {
+    match tokio::net::TcpStream::connect("1.1.1.1:443").await {
+        Ok(_) => ConnectionState::Connected,
+        Err(_) => ConnectionState::Offline,
+    }
+}
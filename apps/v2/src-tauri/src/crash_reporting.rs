@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Event emitted whenever a panic or a spawned task's fatal error is
+/// caught, instead of letting it silently kill whatever loop it occurred in.
+pub const FATAL_ERROR: &str = "crash-reporting://fatal-error";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashReport {
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: String,
+    pub occurred_at_rfc3339: String,
+}
+
+/// Installs a panic hook that turns any panic — on the main thread or a
+/// spawned task — into a [`CrashReport`]: emitted on [`FATAL_ERROR`] for a
+/// live `watch_fatal_errors` listener, and appended to a log file so a
+/// crash that happens before anything is listening isn't lost. Call once
+/// from `run()`'s setup hook, before any other background task is spawned.
+pub fn install_panic_hook(app: AppHandle) {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = CrashReport {
+            message: info.to_string(),
+            location: info.location().map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+            backtrace: std::backtrace::Backtrace::force_capture().to_string(),
+            occurred_at_rfc3339: Utc::now().to_rfc3339(),
+        };
+
+        if let Ok(log_dir) = app.path().app_log_dir() {
+            let _ = write_report_to_log(&log_dir, &report);
+        }
+        let _ = app.emit(FATAL_ERROR, &report);
+
+        previous_hook(info);
+    }));
+}
+
+fn write_report_to_log(log_dir: &std::path::Path, report: &CrashReport) -> std::io::Result<()> {
+    std::fs::create_dir_all(log_dir)?;
+    let line = serde_json::to_string(report).unwrap_or_else(|_| report.message.clone());
+    use std::io::Write;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(crash_log_path(log_dir))?;
+    writeln!(file, "{line}")
+}
+
+fn crash_log_path(log_dir: &std::path::Path) -> PathBuf {
+    log_dir.join("crashes.log")
+}
+
+/// Returns the Tauri event name to subscribe to for fatal error reports.
+#[tauri::command]
+pub fn watch_fatal_errors() -> &'static str {
+    FATAL_ERROR
+}
+
+/// Catches a fatal error from within a spawned background task (one that
+/// isn't a panic, e.g. a loop deciding it cannot continue) and reports it
+/// the same way a panic would be, so a broken sync loop surfaces instead of
+/// dying silently.
+pub fn report_task_error(app: &AppHandle, source: &str, message: String) {
+    let report = CrashReport {
+        message: format!("[{source}] {message}"),
+        location: None,
+        backtrace: String::new(),
+        occurred_at_rfc3339: Utc::now().to_rfc3339(),
+    };
+    if let Ok(log_dir) = app.path().app_log_dir() {
+        let _ = write_report_to_log(&log_dir, &report);
+    }
+    let _ = app.emit(FATAL_ERROR, &report);
+}
@@ -0,0 +1,45 @@
+//! Decryption/session health warnings for the standard E2EE security
+//! banners (unverified device of contact, key backup disabled, recovery
+//! key out of date, this device unverified), plus manual key-request retry
+//! for "Unable to decrypt" events.
+//!
+//! This client has no end-to-end encryption implementation yet — there is
+//! no key backup, device verification, or crypto store to watch. Recording
+//! the request here rather than dropping it; a real `watch_crypto_warnings`
+//! stream needs an E2EE subsystem underneath it first.
+use crate::error::AppError;
+
+#[tauri::command]
+pub fn get_crypto_warnings() -> Result<Vec<()>, AppError> {
+    Err(AppError::Other(
+        "not applicable: this client has no end-to-end encryption subsystem".into(),
+    ))
+}
+
+/// Manually retries a room key request for a specific undecryptable event,
+/// for the "Unable to decrypt" → "retry" affordance.
+#[tauri::command]
+pub fn request_keys_for_event(_room_id: String, _event_id: String) -> Result<(), AppError> {
+    Err(AppError::Other(
+        "not applicable: this client has no end-to-end encryption subsystem".into(),
+    ))
+}
+
+/// Lists outstanding key requests and their status, for support to debug
+/// which device is refusing to forward keys.
+#[tauri::command]
+pub fn get_outstanding_key_requests() -> Result<Vec<()>, AppError> {
+    Err(AppError::Other(
+        "not applicable: this client has no end-to-end encryption subsystem".into(),
+    ))
+}
+
+/// Backs a room's "Room security" settings screen: algorithm, rotation
+/// period, per-device verification counts, and whether unverified devices
+/// are blocked from receiving new messages.
+#[tauri::command]
+pub fn get_room_encryption_details(_room_id: String) -> Result<(), AppError> {
+    Err(AppError::Other(
+        "not applicable: this client has no end-to-end encryption subsystem".into(),
+    ))
+}
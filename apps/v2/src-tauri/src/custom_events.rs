@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+use crate::error::AppError;
+
+/// Event emitted for every custom room event sent or received; the frontend
+/// filters by `room_id`/`event_type` after subscribing, the same way
+/// [`crate::device_messages::TO_DEVICE_MESSAGE`] is filtered, since Tauri's
+/// event system matches on exact names rather than content.
+pub const CUSTOM_ROOM_EVENT: &str = "custom-events://room-event";
+
+/// A generic room event for integrators to build `skiffy.*` features
+/// (shared whiteboards, task lists) on without the core needing a dedicated
+/// command for each one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomRoomEvent {
+    pub room_id: String,
+    pub event_type: String,
+    pub content: serde_json::Value,
+}
+
+/// Sends a custom room event. This client has no send pipeline of its own
+/// to call yet, so like [`crate::device_messages::send_to_device`], delivery
+/// is local-only: emitted on [`CUSTOM_ROOM_EVENT`] for now, ahead of the
+/// real room-send transport landing.
+#[tauri::command]
+pub fn send_custom_event(
+    app: AppHandle,
+    room_id: String,
+    event_type: String,
+    json_content: serde_json::Value,
+) -> Result<(), AppError> {
+    let event = CustomRoomEvent { room_id, event_type, content: json_content };
+    app.emit(CUSTOM_ROOM_EVENT, &event).map_err(|e| AppError::Other(e.to_string()))
+}
+
+/// Returns the Tauri event name to subscribe to for custom room events, and
+/// the `(room_id, event_type)` the frontend should filter incoming events
+/// by, since this channel is shared across all rooms and event types.
+#[tauri::command]
+pub fn watch_custom_events(room_id: String, event_type: String) -> (String, String, String) {
+    (CUSTOM_ROOM_EVENT.to_string(), room_id, event_type)
+}
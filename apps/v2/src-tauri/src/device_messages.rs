@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+use crate::error::AppError;
+
+/// Event emitted for every incoming to-device message; the frontend filters
+/// by `event_type` prefix after subscribing, since Tauri's event system
+/// matches on exact names rather than prefixes.
+pub const TO_DEVICE_MESSAGE: &str = "to-device://message";
+
+/// A generic ephemeral message addressed to one of the user's own other
+/// devices, for custom `skiffy.*` event types like a remote "wipe my
+/// session" command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToDeviceMessage {
+    pub user_id: String,
+    pub device_id: String,
+    pub event_type: String,
+    pub content: serde_json::Value,
+}
+
+/// Sends a one-off to-device message. This client has no server transport
+/// for to-device messages yet, so delivery is local-only: it is emitted on
+/// [`TO_DEVICE_MESSAGE`], which is enough to build and test `skiffy.*`
+/// custom features ahead of the real transport landing.
+#[tauri::command]
+pub fn send_to_device(app: AppHandle, message: ToDeviceMessage) -> Result<(), AppError> {
+    app.emit(TO_DEVICE_MESSAGE, &message).map_err(|e| AppError::Other(e.to_string()))
+}
+
+/// Returns the Tauri event name to subscribe to for to-device messages, and
+/// the prefix the frontend should filter incoming `event_type`s by (e.g.
+/// `skiffy.`) since this channel is shared across all custom event types.
+#[tauri::command]
+pub fn watch_to_device(event_type_prefix: String) -> (String, String) {
+    (TO_DEVICE_MESSAGE.to_string(), event_type_prefix)
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Tracks which room is the direct conversation with each other user,
+/// mirroring what `m.direct` account data does on a Matrix-backed client:
+/// kept in sync here so duplicate DMs don't accumulate over time, and a
+/// stale mapping (the room was left, or the other user is no longer a
+/// member) can be repaired instead of silently misrouting "message this
+/// user" to a dead room.
+#[derive(Default)]
+pub struct DirectRoomRegistry {
+    by_user: Mutex<HashMap<String, String>>,
+}
+
+/// Returns the direct-message room with `user_id`, if one is known.
+#[tauri::command]
+pub fn get_dm_with(
+    state: tauri::State<'_, crate::state::AppState>,
+    user_id: String,
+) -> Option<String> {
+    state.direct_rooms.by_user.lock().unwrap().get(&user_id).cloned()
+}
+
+/// Returns the existing DM with `user_id` if one is already tracked,
+/// otherwise records `room_id` as the new one. Callers are expected to have
+/// already created `room_id` only when this returns it back unchanged;
+/// when an existing room is returned, the freshly created room should be
+/// left aside (or left) rather than used, to avoid duplicate DMs.
+#[tauri::command]
+pub fn create_dm(
+    state: tauri::State<'_, crate::state::AppState>,
+    user_id: String,
+    room_id: String,
+) -> String {
+    state
+        .direct_rooms
+        .by_user
+        .lock()
+        .unwrap()
+        .entry(user_id)
+        .or_insert(room_id)
+        .clone()
+}
+
+/// Marks `room_id` as the direct conversation with `user_id`, for classifying
+/// a room as a DM after the fact (e.g. it has exactly two members and was
+/// marked `is_direct` at invite time).
+#[tauri::command]
+pub fn classify_as_dm(
+    state: tauri::State<'_, crate::state::AppState>,
+    user_id: String,
+    room_id: String,
+) {
+    state.direct_rooms.by_user.lock().unwrap().insert(user_id, room_id);
+}
+
+/// Drops the mapping for `user_id` if it currently points at `stale_room_id`,
+/// so `get_dm_with`/`create_dm` stop returning a room that's been left or
+/// whose other member mapping has gone stale.
+#[tauri::command]
+pub fn repair_stale_dm(
+    state: tauri::State<'_, crate::state::AppState>,
+    user_id: String,
+    stale_room_id: String,
+) {
+    let mut by_user = state.direct_rooms.by_user.lock().unwrap();
+    if by_user.get(&user_id) == Some(&stale_room_id) {
+        by_user.remove(&user_id);
+    }
+}
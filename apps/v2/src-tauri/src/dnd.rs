@@ -0,0 +1,128 @@
+use std::sync::Mutex;
+
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::error::AppError;
+
+/// Event emitted whenever [`set_dnd`] or [`set_quiet_hours`] changes the
+/// do-not-disturb settings, carrying the resulting status. It is not
+/// emitted when a quiet-hours window starts or ends, or a snooze runs out,
+/// on its own; [`get_dnd_status`] reflects those transitions when called.
+pub const DND_STATE_CHANGED: &str = "dnd://state-changed";
+
+/// A recurring quiet-hours window, evaluated in local time, independent of
+/// whatever the OS's own do-not-disturb mode is doing. Times are `HH:MM`
+/// and days are lowercase English weekday names (`"mon"`..`"sun"`), kept as
+/// plain strings rather than `chrono` types so this struct can derive
+/// `Deserialize` without pulling in chrono's `serde` feature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuietHours {
+    pub starts_at: String,
+    pub ends_at: String,
+    pub days_of_week: Vec<String>,
+}
+
+struct ParsedQuietHours {
+    starts_at: NaiveTime,
+    ends_at: NaiveTime,
+    days_of_week: Vec<chrono::Weekday>,
+}
+
+fn parse_quiet_hours(window: &QuietHours) -> Result<ParsedQuietHours, AppError> {
+    let starts_at = NaiveTime::parse_from_str(&window.starts_at, "%H:%M")
+        .map_err(|e| AppError::Other(e.to_string()))?;
+    let ends_at = NaiveTime::parse_from_str(&window.ends_at, "%H:%M")
+        .map_err(|e| AppError::Other(e.to_string()))?;
+    let days_of_week = window
+        .days_of_week
+        .iter()
+        .map(|day| day.parse::<chrono::Weekday>().map_err(|e| AppError::Other(e.to_string())))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(ParsedQuietHours { starts_at, ends_at, days_of_week })
+}
+
+#[derive(Default)]
+pub struct DndState {
+    snoozed_until: Mutex<Option<DateTime<Utc>>>,
+    quiet_hours: Mutex<Vec<QuietHours>>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DndStatus {
+    pub is_active: bool,
+}
+
+fn in_quiet_hours(schedules: &[QuietHours], now: DateTime<Utc>) -> bool {
+    use chrono::Datelike;
+    let local_now = now.with_timezone(&chrono::Local);
+    let local_time = local_now.time();
+    let weekday = local_now.weekday();
+    schedules.iter().filter_map(|window| parse_quiet_hours(window).ok()).any(|window| {
+        window.days_of_week.contains(&weekday)
+            && if window.starts_at <= window.ends_at {
+                local_time >= window.starts_at && local_time < window.ends_at
+            } else {
+                // Window wraps past midnight.
+                local_time >= window.starts_at || local_time < window.ends_at
+            }
+    })
+}
+
+/// Snoozes notifications until `until_rfc3339`, or clears the snooze if
+/// `None`.
+#[tauri::command]
+pub fn set_dnd(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    until_rfc3339: Option<String>,
+) -> Result<(), AppError> {
+    let until = until_rfc3339
+        .map(|s| DateTime::parse_from_rfc3339(&s).map(|dt| dt.with_timezone(&Utc)))
+        .transpose()
+        .map_err(|e| AppError::Other(e.to_string()))?;
+    *state.dnd.snoozed_until.lock().unwrap() = until;
+    crate::streams::coalesced_emit(&app, DND_STATE_CHANGED, current_status(&state));
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_quiet_hours(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    schedules: Vec<QuietHours>,
+) {
+    *state.dnd.quiet_hours.lock().unwrap() = schedules;
+    crate::streams::coalesced_emit(&app, DND_STATE_CHANGED, current_status(&state));
+}
+
+fn current_status(state: &crate::state::AppState) -> DndStatus {
+    DndStatus { is_active: is_dnd_active(state) }
+}
+
+/// Whether nothing should notify right now, per snooze or quiet-hours
+/// schedule. Consulted by the notification decision engine alongside
+/// [`crate::timeline::should_notify`]'s mute-word check.
+pub(crate) fn is_dnd_active(state: &crate::state::AppState) -> bool {
+    let now = Utc::now();
+    if let Some(until) = *state.dnd.snoozed_until.lock().unwrap() {
+        if now < until {
+            return true;
+        }
+    }
+    in_quiet_hours(&state.dnd.quiet_hours.lock().unwrap(), now)
+}
+
+#[tauri::command]
+pub fn get_dnd_status(state: tauri::State<'_, crate::state::AppState>) -> DndStatus {
+    current_status(&state)
+}
+
+/// Returns the Tauri event name to subscribe to for DND state changes.
+#[tauri::command]
+pub fn watch_dnd_state() -> &'static str {
+    DND_STATE_CHANGED
+}
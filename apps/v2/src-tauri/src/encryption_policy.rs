@@ -0,0 +1,23 @@
+//! Send-blocking policy for rooms with unverified devices, and the
+//! "verify or send anyway" dialog's backing data.
+//!
+//! This client has no end-to-end encryption implementation yet (see
+//! [`crate::crypto_health`]), so there are no devices to verify and no
+//! encrypted rooms to gate sending in. Recording the request here rather
+//! than dropping it; a real `set_encryption_policy` needs the E2EE
+//! subsystem underneath it first.
+use crate::error::AppError;
+
+#[tauri::command]
+pub fn set_encryption_policy(_block_unverified: bool) -> Result<(), AppError> {
+    Err(AppError::Other(
+        "not applicable: this client has no end-to-end encryption subsystem".into(),
+    ))
+}
+
+#[tauri::command]
+pub fn get_unverified_devices_in_room(_room_id: String) -> Result<Vec<()>, AppError> {
+    Err(AppError::Other(
+        "not applicable: this client has no end-to-end encryption subsystem".into(),
+    ))
+}
@@ -0,0 +1,91 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+/// Application-wide error type returned from fallible commands. Serializes
+/// to `{ "kind": "other", "messageKey": "error.other", "message": "...",
+/// "params": {...} }`: `kind` is for matching error types in code,
+/// `messageKey` plus `params` let the frontend look up and interpolate a
+/// localized string instead of displaying `message`'s raw English text,
+/// and `message` stays as a fallback for anything not yet localized.
+#[derive(Debug, Error, Clone)]
+pub enum AppError {
+    #[error("{0}")]
+    Other(String),
+    #[error("terms of service must be accepted at {url}")]
+    ConsentRequired { url: String },
+    #[error("{homeserver} is not on this deployment's homeserver allow-list")]
+    ServerNotAllowed { homeserver: String },
+    #[error("guests cannot perform this action; sign up to continue")]
+    GuestNotAllowed,
+    #[error("slow mode is active; try again in {seconds_remaining}s")]
+    CooldownActive { seconds_remaining: u64 },
+    #[error("event content is too large to send ({max_bytes} bytes allowed)")]
+    TooLarge { max_bytes: usize },
+    #[error("this room was upgraded; its successor is {new_room_id}")]
+    RoomUpgraded { new_room_id: String },
+}
+
+impl AppError {
+    /// Snake-case variant name, for code that wants to match on error type
+    /// without pattern-matching the whole enum (e.g. across the IPC
+    /// boundary, where only the serialized form is visible).
+    fn kind(&self) -> &'static str {
+        match self {
+            AppError::Other(_) => "other",
+            AppError::ConsentRequired { .. } => "consent_required",
+            AppError::ServerNotAllowed { .. } => "server_not_allowed",
+            AppError::GuestNotAllowed => "guest_not_allowed",
+            AppError::CooldownActive { .. } => "cooldown_active",
+            AppError::TooLarge { .. } => "too_large",
+            AppError::RoomUpgraded { .. } => "room_upgraded",
+        }
+    }
+
+    /// Stable localization lookup key, derived from `kind` so there's one
+    /// place mapping a variant to its identity instead of two lists to
+    /// keep in sync.
+    fn message_key(&self) -> String {
+        format!("error.{}", self.kind())
+    }
+
+    /// Structured parameters a localized message template can interpolate
+    /// (e.g. "error.cooldown_active" -> "Try again in {secondsRemaining}s"),
+    /// so the frontend doesn't have to parse them back out of `message`.
+    fn params(&self) -> serde_json::Value {
+        match self {
+            AppError::Other(message) => serde_json::json!({ "message": message }),
+            AppError::ConsentRequired { url } => serde_json::json!({ "url": url }),
+            AppError::ServerNotAllowed { homeserver } => serde_json::json!({ "homeserver": homeserver }),
+            AppError::GuestNotAllowed => serde_json::json!({}),
+            AppError::CooldownActive { seconds_remaining } => {
+                serde_json::json!({ "secondsRemaining": seconds_remaining })
+            }
+            AppError::TooLarge { max_bytes } => serde_json::json!({ "maxBytes": max_bytes }),
+            AppError::RoomUpgraded { new_room_id } => serde_json::json!({ "newRoomId": new_room_id }),
+        }
+    }
+}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 4)?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("messageKey", &self.message_key())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("params", &self.params())?;
+        state.end()
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        AppError::Other(err.to_string())
+    }
+}
+
+impl From<rusqlite::Error> for AppError {
+    fn from(err: rusqlite::Error) -> Self {
+        AppError::Other(err.to_string())
+    }
+}
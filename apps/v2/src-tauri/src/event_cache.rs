@@ -0,0 +1,218 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// A single cached timeline event, enough to render it without hitting the
+/// network again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedEvent {
+    pub event_id: String,
+    pub room_id: String,
+    pub received_order: i64,
+    pub content_json: String,
+    pub pinned: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvictionPolicy {
+    pub max_events_per_room: u32,
+    pub max_total_db_bytes: u64,
+}
+
+/// Bounded on-disk cache of timeline events, so a long-lived install's
+/// local database doesn't grow without limit. Eviction keeps the latest
+/// `max_events_per_room` per room (by `received_order`) and never evicts a
+/// pinned event, running on `prune_event_cache` rather than on every
+/// insert so a burst of inbound events doesn't pay the eviction cost per
+/// event.
+pub struct EventCache {
+    conn: Mutex<Connection>,
+}
+
+impl EventCache {
+    /// Gives other modules (e.g. [`crate::event_debug`]) read access to
+    /// cached raw event JSON without duplicating the cache itself.
+    pub(crate) fn connection(&self) -> &Mutex<Connection> {
+        &self.conn
+    }
+}
+
+impl Default for EventCache {
+    fn default() -> Self {
+        let conn = Connection::open_in_memory().expect("failed to open in-memory event cache db");
+        init_schema(&conn).expect("failed to initialize event cache schema");
+        EventCache { conn: Mutex::new(conn) }
+    }
+}
+
+fn init_schema(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS cached_events (
+            event_id TEXT PRIMARY KEY,
+            room_id TEXT NOT NULL,
+            received_order INTEGER NOT NULL,
+            content_json TEXT NOT NULL,
+            pinned INTEGER NOT NULL
+        )",
+        [],
+    )?;
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_room_order ON cached_events (room_id, received_order)", [])?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn open_event_cache(
+    state: tauri::State<'_, crate::state::AppState>,
+    db_path: PathBuf,
+    encryption_key: String,
+) -> Result<(), AppError> {
+    let conn = Connection::open(db_path)?;
+    conn.pragma_update(None, "key", encryption_key)?;
+    init_schema(&conn)?;
+    *state.event_cache.conn.lock().unwrap() = conn;
+    Ok(())
+}
+
+/// Persists `event`, unless the room's history visibility says the current
+/// user shouldn't see events from before they joined — in which case it's
+/// silently dropped rather than cached and later surfaced.
+#[tauri::command]
+pub fn cache_event(state: tauri::State<'_, crate::state::AppState>, event: CachedEvent) -> Result<(), AppError> {
+    if crate::history_visibility::filter_visible(&state, &event.room_id, vec![event.clone()]).is_empty() {
+        return Ok(());
+    }
+
+    state.event_cache.conn.lock().unwrap().execute(
+        "INSERT OR REPLACE INTO cached_events (event_id, room_id, received_order, content_json, pinned)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![event.event_id, event.room_id, event.received_order, event.content_json, event.pinned],
+    )?;
+    Ok(())
+}
+
+/// Evicts the oldest unpinned events beyond `policy.max_events_per_room`
+/// for every room, then — if the database file is still over
+/// `policy.max_total_db_bytes` — evicts further unpinned events globally,
+/// oldest first, until it fits or nothing unpinned is left.
+#[tauri::command]
+pub fn prune_event_cache(
+    state: tauri::State<'_, crate::state::AppState>,
+    db_path: PathBuf,
+    policy: EvictionPolicy,
+) -> Result<u64, AppError> {
+    let conn = state.event_cache.conn.lock().unwrap();
+    prune(&conn, &db_path, &policy)
+}
+
+fn prune(conn: &Connection, db_path: &std::path::Path, policy: &EvictionPolicy) -> Result<u64, AppError> {
+    let mut evicted = 0u64;
+
+    let room_ids: Vec<String> = conn
+        .prepare("SELECT DISTINCT room_id FROM cached_events")?
+        .query_map([], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    for room_id in room_ids {
+        evicted += conn.execute(
+            "DELETE FROM cached_events WHERE room_id = ?1 AND pinned = 0 AND event_id NOT IN (
+                SELECT event_id FROM cached_events WHERE room_id = ?1
+                ORDER BY received_order DESC LIMIT ?2
+            )",
+            params![room_id, policy.max_events_per_room],
+        )? as u64;
+    }
+
+    while std::fs::metadata(db_path).map(|m| m.len()).unwrap_or(0) > policy.max_total_db_bytes {
+        let deleted = conn.execute(
+            "DELETE FROM cached_events WHERE event_id = (
+                SELECT event_id FROM cached_events WHERE pinned = 0
+                ORDER BY received_order ASC LIMIT 1
+            )",
+            [],
+        )?;
+        if deleted == 0 {
+            break;
+        }
+        evicted += deleted as u64;
+        conn.execute("VACUUM", [])?;
+    }
+
+    Ok(evicted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_db_path(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("skiffy_event_cache_test_{label}_{:?}", std::thread::current().id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join("events.db")
+    }
+
+    fn insert_event(conn: &Connection, event_id: &str, room_id: &str, received_order: i64, pinned: bool) {
+        conn.execute(
+            "INSERT INTO cached_events (event_id, room_id, received_order, content_json, pinned) VALUES (?1, ?2, ?3, '{}', ?4)",
+            params![event_id, room_id, received_order, pinned],
+        )
+        .unwrap();
+    }
+
+    fn event_ids(conn: &Connection) -> Vec<String> {
+        let mut stmt = conn.prepare("SELECT event_id FROM cached_events ORDER BY event_id").unwrap();
+        stmt.query_map([], |row| row.get(0)).unwrap().collect::<rusqlite::Result<_>>().unwrap()
+    }
+
+    #[test]
+    fn prune_keeps_only_the_newest_events_per_room() {
+        let path = temp_db_path("per_room");
+        let conn = Connection::open(&path).unwrap();
+        init_schema(&conn).unwrap();
+        for i in 0..5 {
+            insert_event(&conn, &format!("$e{i}"), "!room:example.org", i, false);
+        }
+
+        let policy = EvictionPolicy { max_events_per_room: 2, max_total_db_bytes: u64::MAX };
+        let evicted = prune(&conn, &path, &policy).unwrap();
+
+        assert_eq!(evicted, 3);
+        assert_eq!(event_ids(&conn), vec!["$e3".to_string(), "$e4".to_string()]);
+    }
+
+    #[test]
+    fn prune_never_evicts_a_pinned_event() {
+        let path = temp_db_path("pinned");
+        let conn = Connection::open(&path).unwrap();
+        init_schema(&conn).unwrap();
+        insert_event(&conn, "$old_pinned", "!room:example.org", 0, true);
+        insert_event(&conn, "$new", "!room:example.org", 1, false);
+
+        let policy = EvictionPolicy { max_events_per_room: 0, max_total_db_bytes: u64::MAX };
+        prune(&conn, &path, &policy).unwrap();
+
+        assert_eq!(event_ids(&conn), vec!["$old_pinned".to_string()]);
+    }
+
+    #[test]
+    fn prune_evicts_unpinned_events_oldest_first_when_over_the_byte_budget() {
+        let path = temp_db_path("byte_budget");
+        let conn = Connection::open(&path).unwrap();
+        init_schema(&conn).unwrap();
+        for i in 0..20 {
+            insert_event(&conn, &format!("$e{i}"), "!room:example.org", i, false);
+        }
+
+        let policy = EvictionPolicy { max_events_per_room: u32::MAX, max_total_db_bytes: 0 };
+        let evicted = prune(&conn, &path, &policy).unwrap();
+
+        assert_eq!(evicted, 20);
+        assert!(event_ids(&conn).is_empty());
+    }
+}
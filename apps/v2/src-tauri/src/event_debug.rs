@@ -0,0 +1,49 @@
+//! The "View source" feature power users and developers rely on: raw event
+//! JSON plus whatever decryption and federation metadata is available for
+//! it.
+use rusqlite::params;
+use serde::Serialize;
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventDebugInfo {
+    pub raw_json: String,
+    /// `None` until this client has an end-to-end encryption subsystem (see
+    /// [`crate::crypto_health`]) to report a session id, sender device and
+    /// trust state from.
+    pub decryption_info: Option<DecryptionInfo>,
+    /// `None` until this client has a federation/transport layer to report
+    /// which server an event was first seen from.
+    pub federation_origin: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecryptionInfo {
+    pub session_id: String,
+    pub sender_device: String,
+    pub trusted: bool,
+}
+
+/// Looks up the raw event JSON from [`crate::event_cache`] — the one piece
+/// of this request this client can actually answer — and reports the rest
+/// as unavailable rather than guessing.
+#[tauri::command]
+pub fn get_event_debug_info(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    event_id: String,
+) -> Result<EventDebugInfo, AppError> {
+    let conn = state.event_cache.connection().lock().unwrap();
+    let raw_json: String = conn
+        .query_row(
+            "SELECT content_json FROM cached_events WHERE room_id = ?1 AND event_id = ?2",
+            params![room_id, event_id],
+            |row| row.get(0),
+        )
+        .map_err(|_| AppError::Other("event not found in local cache".into()))?;
+
+    Ok(EventDebugInfo { raw_json, decryption_info: None, federation_origin: None })
+}
@@ -0,0 +1,121 @@
+use crate::error::AppError;
+
+/// Matrix federation's longstanding hard limit on a PDU's total serialized
+/// size; a receiving server rejects anything larger with a bare 413.
+pub const MAX_EVENT_BYTES: usize = 65_536;
+
+/// Headroom reserved below [`MAX_EVENT_BYTES`] for Megolm's ciphertext
+/// padding and the surrounding `m.room.encrypted` envelope, so a content
+/// body that just barely fits unencrypted doesn't get rejected once wrapped.
+const ENCRYPTION_OVERHEAD_BYTES: usize = 512;
+
+/// The largest an event's `content` field (serialized as JSON) may be
+/// before it risks tripping the federation limit once encrypted.
+pub const MAX_CONTENT_BYTES: usize = MAX_EVENT_BYTES - ENCRYPTION_OVERHEAD_BYTES;
+
+/// Checks that `content_json` fits under [`MAX_CONTENT_BYTES`], so an
+/// oversized send fails fast with a typed [`AppError::TooLarge`] instead of
+/// surfacing as an opaque server 413 after a round trip.
+#[tauri::command]
+pub fn check_event_size(content_json: String) -> Result<(), AppError> {
+    if content_json.len() > MAX_CONTENT_BYTES {
+        return Err(AppError::TooLarge { max_bytes: MAX_CONTENT_BYTES });
+    }
+    Ok(())
+}
+
+/// Splits `body` into chunks that each stay under [`MAX_CONTENT_BYTES`]
+/// once accounting for the rest of an `m.text` content's JSON overhead, so
+/// a single long paste can be sent as several ordinary messages instead of
+/// one oversized event. Breaks on the nearest preceding newline or space
+/// to the limit where one exists, so words aren't split mid-way.
+#[tauri::command]
+pub fn split_oversized_text(body: String) -> Vec<String> {
+    const CONTENT_OVERHEAD_BYTES: usize = 32; // `{"msgtype":"m.text","body":""}` and escaping slack
+    let max_chunk_bytes = MAX_CONTENT_BYTES - CONTENT_OVERHEAD_BYTES;
+    let body = body.as_str();
+
+    if body.len() <= max_chunk_bytes {
+        return vec![body.to_string()];
+    }
+
+    let mut chunks = Vec::new();
+    let mut remaining = body;
+    while remaining.len() > max_chunk_bytes {
+        let mut split_at = floor_char_boundary(remaining, max_chunk_bytes);
+        if let Some(break_at) = remaining[..split_at].rfind(['\n', ' ']) {
+            if break_at > 0 {
+                split_at = break_at + 1;
+            }
+        }
+        chunks.push(remaining[..split_at].to_string());
+        remaining = &remaining[split_at..];
+    }
+    if !remaining.is_empty() {
+        chunks.push(remaining.to_string());
+    }
+    chunks
+}
+
+/// `str::floor_char_boundary` is still nightly-only; this is the stable
+/// equivalent for finding the largest valid UTF-8 boundary at or before
+/// `index`.
+pub(crate) fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while index > 0 && !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_event_size_allows_content_under_the_limit() {
+        assert!(check_event_size("a".repeat(100)).is_ok());
+    }
+
+    #[test]
+    fn check_event_size_rejects_content_over_the_limit() {
+        let result = check_event_size("a".repeat(MAX_CONTENT_BYTES + 1));
+        assert!(matches!(result, Err(AppError::TooLarge { .. })));
+    }
+
+    #[test]
+    fn split_oversized_text_leaves_short_bodies_untouched() {
+        assert_eq!(split_oversized_text("hello".to_string()), vec!["hello".to_string()]);
+    }
+
+    #[test]
+    fn split_oversized_text_breaks_on_word_boundaries() {
+        let word = "a".repeat(100);
+        let body = std::iter::repeat(word.clone()).take(1000).collect::<Vec<_>>().join(" ");
+        let chunks = split_oversized_text(body.clone());
+
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(chunk.len() <= MAX_CONTENT_BYTES);
+        }
+        assert_eq!(chunks.join(""), body);
+    }
+
+    #[test]
+    fn split_oversized_text_does_not_split_a_multibyte_char() {
+        let body = "😀".repeat(MAX_CONTENT_BYTES);
+        let chunks = split_oversized_text(body);
+        for chunk in chunks {
+            assert!(chunk.is_char_boundary(chunk.len()));
+            assert!(chunk.chars().all(|c| c == '😀'));
+        }
+    }
+
+    #[test]
+    fn floor_char_boundary_backs_up_to_a_valid_boundary() {
+        let s = "é"; // 2-byte UTF-8 character
+        assert_eq!(floor_char_boundary(s, 1), 0);
+        assert_eq!(floor_char_boundary(s, 2), 2);
+        assert_eq!(floor_char_boundary(s, 100), 2);
+    }
+}
@@ -0,0 +1,72 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportableEvent {
+    pub event_id: String,
+    pub sender: String,
+    pub body: String,
+    pub timestamp_ms: i64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Json,
+    Html,
+}
+
+/// Writes a room's (already decrypted and paginated) history to a single
+/// file in `output_dir`, for compliance/archival exports or leaving a
+/// server. Referenced media is not fetched here yet — `events` carry
+/// whatever URLs the caller already resolved.
+#[tauri::command]
+pub fn export_room_history(
+    room_id: String,
+    format: ExportFormat,
+    events: Vec<ExportableEvent>,
+    output_dir: PathBuf,
+) -> Result<PathBuf, AppError> {
+    fs::create_dir_all(&output_dir)?;
+    let extension = match format {
+        ExportFormat::Json => "json",
+        ExportFormat::Html => "html",
+    };
+    let dest = output_dir.join(format!("{room_id}.{extension}"));
+
+    let rendered = match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&events)
+            .map_err(|e| AppError::Other(e.to_string()))?,
+        ExportFormat::Html => render_html(&room_id, &events),
+    };
+
+    fs::write(&dest, rendered)?;
+    Ok(dest)
+}
+
+fn render_html(room_id: &str, events: &[ExportableEvent]) -> String {
+    let mut html = format!(
+        "<!doctype html><html><head><meta charset=\"utf-8\"><title>{room_id}</title></head><body>\n"
+    );
+    for event in events {
+        html.push_str(&format!(
+            "<p><strong>{}</strong>: {}</p>\n",
+            html_escape(&event.sender),
+            html_escape(&event.body)
+        ));
+    }
+    html.push_str("</body></html>\n");
+    html
+}
+
+fn html_escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,12 @@
+//! A stable `extern "C"` cdylib interface (with a generated header) over
+//! the core API, so non-Flutter consumers (a CLI, Swift/Kotlin native
+//! modules, tests) could link against this crate without flutter_rust_bridge.
+//!
+//! This tree doesn't use flutter_rust_bridge to begin with — it's a Tauri
+//! application, and every command in this crate is already exposed via
+//! Tauri's own IPC (`#[tauri::command]` + `invoke_handler!`), not FRB
+//! bindings. There's no FRB layer for a C ABI to sit "in addition to", and
+//! adding one would mean duplicating every command's signature behind
+//! `extern "C"` for a consumption model (linking this crate as a cdylib)
+//! this app was never built around. Recording the request here rather than
+//! dropping it.
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use rusqlite::OptionalExtension;
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::event_cache::CachedEvent;
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryVisibility {
+    WorldReadable,
+    Shared,
+    Invited,
+    Joined,
+}
+
+/// Per-room `history_visibility` and the `received_order` the user's join
+/// landed at, so pagination and permalink resolution can tell whether an
+/// earlier-cached event is actually visible to this user rather than just
+/// present in the local cache (e.g. synced before a `history_visibility:
+/// joined` room was joined, or left over from before visibility was
+/// tightened).
+#[derive(Default)]
+pub struct HistoryVisibilityState {
+    visibility: Mutex<HashMap<String, HistoryVisibility>>,
+    joined_at_order: Mutex<HashMap<String, i64>>,
+}
+
+#[tauri::command]
+pub fn set_room_history_visibility(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    visibility: HistoryVisibility,
+) {
+    state.history_visibility.visibility.lock().unwrap().insert(room_id, visibility);
+}
+
+/// Records that the user's join in `room_id` landed at `received_order`,
+/// so events cached before that point can be told apart from events
+/// received since.
+#[tauri::command]
+pub fn record_room_join_order(state: tauri::State<'_, crate::state::AppState>, room_id: String, received_order: i64) {
+    state.history_visibility.joined_at_order.lock().unwrap().insert(room_id, received_order);
+}
+
+fn is_order_visible(state: &crate::state::AppState, room_id: &str, received_order: i64) -> bool {
+    let visibility = state
+        .history_visibility
+        .visibility
+        .lock()
+        .unwrap()
+        .get(room_id)
+        .copied()
+        .unwrap_or(HistoryVisibility::Shared);
+
+    if matches!(visibility, HistoryVisibility::WorldReadable | HistoryVisibility::Shared) {
+        return true;
+    }
+
+    match state.history_visibility.joined_at_order.lock().unwrap().get(room_id).copied() {
+        Some(joined_at) => received_order >= joined_at,
+        None => false,
+    }
+}
+
+/// Filters `events` down to those the current user may view under
+/// `room_id`'s history visibility, for a pagination response to drop
+/// before the events ever reach local persistence or the UI.
+pub fn filter_visible(state: &crate::state::AppState, room_id: &str, events: Vec<CachedEvent>) -> Vec<CachedEvent> {
+    events.into_iter().filter(|event| is_order_visible(state, room_id, event.received_order)).collect()
+}
+
+pub(crate) fn can_view_event_for(state: &crate::state::AppState, room_id: &str, event_id: &str) -> Result<bool, AppError> {
+    let received_order: Option<i64> = state
+        .event_cache
+        .connection()
+        .lock()
+        .unwrap()
+        .query_row(
+            "SELECT received_order FROM cached_events WHERE room_id = ?1 AND event_id = ?2",
+            rusqlite::params![room_id, event_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+
+    let Some(received_order) = received_order else {
+        return Ok(false);
+    };
+    Ok(is_order_visible(state, room_id, received_order))
+}
+
+/// Whether `event_id` in `room_id` should be visible to the current user
+/// given the room's history visibility and when they joined, used both to
+/// filter pagination and by [`crate::permalink::load_timeline_around_event`]
+/// to show an informative "history not visible" state instead of a bare
+/// not-found error.
+#[tauri::command]
+pub fn can_view_event(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    event_id: String,
+) -> Result<bool, AppError> {
+    can_view_event_for(&state, &room_id, &event_id)
+}
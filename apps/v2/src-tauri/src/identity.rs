@@ -0,0 +1,69 @@
+//! Third-party (email/phone) invites and contact discovery via an identity
+//! server's 3PID lookup/invite APIs.
+//!
+//! This client has no identity-server integration yet — no discovery, no
+//! terms-of-service acceptance flow for one, and no hashed-lookup pepper
+//! exchange. Recording the request here rather than dropping it; a real
+//! `invite_by_email` needs identity-server discovery and consent wired up
+//! first.
+use sha2::{Digest, Sha256};
+
+use crate::error::AppError;
+
+#[tauri::command]
+pub fn invite_by_email(_room_id: String, _email: String) -> Result<(), AppError> {
+    Err(AppError::Other(
+        "not applicable: this client has no identity-server integration".into(),
+    ))
+}
+
+/// Hashes a contact identifier (email or phone, already normalized by the
+/// caller) with the given pepper the same way the identity server's v2
+/// hashed-lookup API expects, so the raw contact never needs to leave the
+/// device unhashed. This is the one piece of the flow that doesn't depend
+/// on an identity server existing.
+fn hash_contact(identifier: &str, pepper: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(identifier.as_bytes());
+    hasher.update(b" ");
+    hasher.update(pepper.as_bytes());
+    base64_url(&hasher.finalize())
+}
+
+fn base64_url(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    let mut out = String::with_capacity((bytes.len() * 4).div_ceil(3));
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let n = (b0 << 16) | (b1 << 8) | b2;
+        out.push(ALPHABET[(n >> 18 & 0x3f) as usize] as char);
+        out.push(ALPHABET[(n >> 12 & 0x3f) as usize] as char);
+        if chunk.len() > 1 {
+            out.push(ALPHABET[(n >> 6 & 0x3f) as usize] as char);
+        }
+        if chunk.len() > 2 {
+            out.push(ALPHABET[(n & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+/// Hashes the caller's contact list so it's ready for an identity server's
+/// hashed-lookup API, then fails the same way `invite_by_email` does: this
+/// client has no identity-server discovery yet, so there is nowhere to send
+/// the hashes.
+#[tauri::command]
+pub fn lookup_contacts(
+    hashed_emails_and_phones: Vec<String>,
+    pepper: String,
+) -> Result<Vec<String>, AppError> {
+    let _hashed: Vec<String> = hashed_emails_and_phones
+        .iter()
+        .map(|identifier| hash_contact(identifier, &pepper))
+        .collect();
+    Err(AppError::Other(
+        "not applicable: this client has no identity-server integration".into(),
+    ))
+}
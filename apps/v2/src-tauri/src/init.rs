@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use tauri::{AppHandle, Manager};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitConfig {
+    pub data_dir: Option<PathBuf>,
+    pub log_level: String,
+    pub bandwidth_mode: crate::bandwidth::BandwidthMode,
+}
+
+/// Single explicit entrypoint for everything the app needs set up before
+/// any other command is safe to call: data directory, logging, secure
+/// storage, network config, session restoration, and sync startup — in that
+/// order, and in one place, instead of the implicit ordering callers used to
+/// have to get right themselves (forgetting a step used to surface as an
+/// unrelated "not initialized" error much later).
+///
+/// Session restoration and sync startup are no-ops for now: this client has
+/// no persisted session or sync engine yet. They're sequenced here anyway
+/// so call sites don't have to change again once those subsystems land.
+/// Logging setup only creates a `logs` directory under the data directory;
+/// `log_level` is not applied.
+#[tauri::command]
+pub async fn init_app(app: AppHandle, config: InitConfig) -> Result<(), AppError> {
+    let data_dir = match config.data_dir {
+        Some(dir) => dir,
+        None => app.path().app_data_dir().map_err(|e| AppError::Other(e.to_string()))?,
+    };
+    std::fs::create_dir_all(&data_dir)?;
+
+    configure_logging(&app, &data_dir, &config.log_level)?;
+
+    let state = app.state::<crate::state::AppState>();
+    state.secure_storage.install(crate::secure_storage::SecureStorage::new(&data_dir));
+    crate::bandwidth::set_bandwidth_mode(state, config.bandwidth_mode);
+
+    restore_sessions().await?;
+    start_sync().await?;
+
+    Ok(())
+}
+
+fn configure_logging(app: &AppHandle, data_dir: &std::path::Path, level: &str) -> Result<(), AppError> {
+    let _ = (app, level);
+    std::fs::create_dir_all(data_dir.join("logs"))?;
+    Ok(())
+}
+
+/// Restores every persisted account's session. No-op until session
+/// persistence exists.
+async fn restore_sessions() -> Result<(), AppError> {
+    Ok(())
+}
+
+/// Starts the sync loop for every restored account. No-op until a sync
+/// engine exists.
+async fn start_sync() -> Result<(), AppError> {
+    Ok(())
+}
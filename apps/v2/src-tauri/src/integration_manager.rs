@@ -0,0 +1,28 @@
+//! A Scalar-compatible integration manager client: listing available
+//! bridges/bots, provisioning a bridge into a room, and managing tokens —
+//! aimed at self-hosted community deployments that use Dimension.
+//!
+//! This client has no integration-manager discovery or token scope yet
+//! (it would need the same kind of OpenID handshake [`crate::openid`]
+//! exists for, plus a manager base URL to talk to). Recording the request
+//! here rather than dropping it; a real implementation needs that
+//! discovery and auth wired up first.
+use crate::error::AppError;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationListing {
+    pub integration_id: String,
+    pub name: String,
+    pub description: String,
+}
+
+#[tauri::command]
+pub fn list_available_integrations() -> Result<Vec<IntegrationListing>, AppError> {
+    Err(AppError::Other("not applicable: this client has no integration-manager integration".into()))
+}
+
+#[tauri::command]
+pub fn provision_integration(_room_id: String, _integration_id: String) -> Result<(), AppError> {
+    Err(AppError::Other("not applicable: this client has no integration-manager integration".into()))
+}
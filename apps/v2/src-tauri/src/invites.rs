@@ -0,0 +1,100 @@
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Configurable policy for how invites from other users are handled,
+/// enforced before an invite is ever surfaced to the user.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitePolicy {
+    #[default]
+    AcceptAll,
+    OnlyKnownUsers,
+    OnlySameServer,
+    Manual,
+}
+
+/// A pending invite filtered out by the active [`InvitePolicy`], kept around
+/// so the user can review what got auto-declined from settings.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilteredInvite {
+    pub room_id: String,
+    pub inviter_user_id: String,
+    pub reason: String,
+}
+
+#[derive(Default)]
+pub struct InviteFilter {
+    policy: Mutex<InvitePolicy>,
+    known_users: Mutex<std::collections::HashSet<String>>,
+    log: Mutex<Vec<FilteredInvite>>,
+}
+
+#[tauri::command]
+pub fn set_invite_policy(state: tauri::State<'_, crate::state::AppState>, policy: InvitePolicy) {
+    *state.invites.policy.lock().unwrap() = policy;
+}
+
+#[tauri::command]
+pub fn get_invite_policy(state: tauri::State<'_, crate::state::AppState>) -> InvitePolicy {
+    *state.invites.policy.lock().unwrap()
+}
+
+/// Marks `user_id` as known, so `OnlyKnownUsers` will accept invites from
+/// them. Intended to be populated from the user's existing DM/contact list
+/// as rooms are joined, not maintained by hand.
+#[tauri::command]
+pub fn mark_user_known(state: tauri::State<'_, crate::state::AppState>, user_id: String) {
+    state.invites.known_users.lock().unwrap().insert(user_id);
+}
+
+fn server_name(user_id: &str) -> Option<&str> {
+    user_id.split_once(':').map(|(_, server)| server)
+}
+
+/// Evaluates an incoming invite against the active policy. Returns `Ok(())`
+/// if it should be surfaced to the user, or `Err` with the reason it was
+/// filtered (and records it in the filtered-invites log) otherwise. This
+/// client has no invite-reporting transport yet, so "optional report" is
+/// limited to this local log for now.
+#[tauri::command]
+pub fn evaluate_invite(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    inviter_user_id: String,
+    own_user_id: String,
+) -> Result<(), AppError> {
+    let policy = *state.invites.policy.lock().unwrap();
+    let allowed = match policy {
+        InvitePolicy::AcceptAll => true,
+        InvitePolicy::Manual => true,
+        InvitePolicy::OnlyKnownUsers => {
+            state.invites.known_users.lock().unwrap().contains(&inviter_user_id)
+        }
+        InvitePolicy::OnlySameServer => {
+            server_name(&inviter_user_id).is_some() && server_name(&inviter_user_id) == server_name(&own_user_id)
+        }
+    };
+
+    if allowed {
+        return Ok(());
+    }
+
+    let reason = format!("invite from {inviter_user_id} blocked by {policy:?} policy");
+    state.invites.log.lock().unwrap().push(FilteredInvite {
+        room_id,
+        inviter_user_id,
+        reason: reason.clone(),
+    });
+    Err(AppError::Other(reason))
+}
+
+/// Returns every invite the active policy has auto-declined, for the
+/// "filtered invites" list in settings.
+#[tauri::command]
+pub fn get_filtered_invites(state: tauri::State<'_, crate::state::AppState>) -> Vec<FilteredInvite> {
+    state.invites.log.lock().unwrap().clone()
+}
@@ -0,0 +1,43 @@
+//! Setting `join_rule = restricted` with allow conditions referencing a
+//! space, which requires checking the target room version actually
+//! supports restricted joins (MSC3083, stable in room versions 8+) before
+//! ever writing the state event.
+//!
+//! This client has no state-event write pipeline yet (see [`crate::spaces`]
+//! for the same gap blocking space management), so there is nowhere to
+//! send the `m.room.join_rules` event even once its shape and the
+//! capability check below are right. The capability check is the one part
+//! of this request that doesn't depend on a homeserver round trip, so it's
+//! implemented for real.
+use crate::error::AppError;
+
+const MIN_RESTRICTED_JOIN_ROOM_VERSION: u32 = 8;
+
+/// Checks whether `room_version` (as reported by the homeserver's
+/// `/capabilities` or a room's `m.room.create` content) supports restricted
+/// joins, returning an error whose message names the unsupported version
+/// and the minimum that supports them, rather than leaving the caller to
+/// infer it from a generic homeserver rejection. The error is an
+/// `AppError::Other`, not a dedicated variant.
+pub fn check_room_version_supports_restricted_join(room_version: &str) -> Result<(), AppError> {
+    let version: u32 = room_version
+        .parse()
+        .map_err(|_| AppError::Other(format!("unrecognized room version: {room_version}")))?;
+    if version >= MIN_RESTRICTED_JOIN_ROOM_VERSION {
+        Ok(())
+    } else {
+        Err(AppError::Other(format!(
+            "room version {room_version} does not support restricted joins; needs version {MIN_RESTRICTED_JOIN_ROOM_VERSION} or later"
+        )))
+    }
+}
+
+#[tauri::command]
+pub fn set_restricted_join_rule(
+    _room_id: String,
+    room_version: String,
+    _allowed_space_ids: Vec<String>,
+) -> Result<(), AppError> {
+    check_room_version_supports_restricted_join(&room_version)?;
+    Err(AppError::Other("not applicable: this client has no state-event write pipeline".into()))
+}
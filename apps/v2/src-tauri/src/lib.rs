@@ -1,3 +1,85 @@
+#[cfg(feature = "synapse-admin")]
+mod admin;
+mod account;
+mod accounts;
+mod animated_media;
+mod auth;
+mod background_sync;
+mod bandwidth;
+mod blurhash;
+mod bridge_awareness;
+mod chunked_upload;
+mod clock_skew;
+mod composer_text;
+mod connection;
+mod crash_reporting;
+mod crypto_health;
+mod custom_events;
+mod device_messages;
+mod direct_rooms;
+mod dnd;
+mod encryption_policy;
+mod event_cache;
+mod event_debug;
+mod event_size_guard;
+mod export;
+mod ffi_layer;
+mod history_visibility;
+mod identity;
+mod init;
+mod integration_manager;
+mod invites;
+mod join_rules;
+mod openid;
+mod policy_lists;
+mod privacy_proxy;
+mod push;
+mod retention;
+mod room_alias;
+mod room_archive;
+mod room_audit;
+mod room_join;
+mod room_list_sections;
+mod room_name;
+mod room_permissions;
+mod room_preload;
+mod room_upgrades;
+mod scheduled_messages;
+mod secure_storage;
+mod security_alerts;
+mod self_destruct;
+mod server_announcements;
+mod session_import;
+mod settings;
+mod send_queue;
+mod slow_mode;
+mod sender_display;
+mod server_acl;
+mod server_policy;
+mod server_probe;
+mod spaces;
+mod storage_usage;
+mod streams;
+mod sync_checkpoint;
+mod timeline;
+mod translation;
+mod widgets;
+mod error;
+mod media;
+mod media_autodownload;
+mod matrix_client_test_harness;
+mod media_metadata;
+mod members;
+mod messages;
+mod metrics;
+mod notification_dismissal;
+mod notification_settings;
+mod permalink;
+mod read_markers;
+mod registration;
+mod state;
+mod upload;
+
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -8,7 +90,201 @@ fn greet(name: &str) -> String {
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![greet])
+        .manage(state::AppState::default())
+        .setup(|app| {
+            crash_reporting::install_panic_hook(app.handle().clone());
+            connection::spawn_monitor(app.handle().clone());
+            scheduled_messages::spawn_scheduler(app.handle().clone());
+            self_destruct::spawn_reaper(app.handle().clone());
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            connection::get_connection_state,
+            media::get_avatar_thumbnail,
+            media::compute_blurhash,
+            media::decode_blurhash,
+            upload::process_attachment,
+            media_metadata::extract_av_metadata,
+            messages::pin_message,
+            messages::unpin_message,
+            messages::get_pinned_messages,
+            messages::star_message,
+            messages::unstar_message,
+            messages::get_starred_messages,
+            permalink::resolve_permalink,
+            permalink::parse_deep_link,
+            permalink::load_timeline_around_event,
+            read_markers::set_read_marker,
+            read_markers::get_first_unread_event,
+            read_markers::set_read_receipt_mode,
+            read_markers::get_read_receipt_mode,
+            export::export_room_history,
+            push::register_unified_push,
+            retention::set_room_retention,
+            retention::prune_expired_media,
+            storage_usage::get_storage_usage,
+            storage_usage::prune_storage,
+            members::ensure_members_loaded,
+            members::set_room_members,
+            members::get_room_members_page,
+            members::search_room_members,
+            room_name::compute_room_display_info,
+            bandwidth::set_bandwidth_mode,
+            bandwidth::get_bandwidth_mode,
+            crypto_health::get_crypto_warnings,
+            crypto_health::request_keys_for_event,
+            crypto_health::get_outstanding_key_requests,
+            crypto_health::get_room_encryption_details,
+            crash_reporting::watch_fatal_errors,
+            metrics::set_telemetry_opt_in,
+            metrics::record_counter,
+            metrics::record_histogram,
+            metrics::get_metrics_snapshot,
+            metrics::export_metrics_snapshot,
+            server_policy::set_server_policy,
+            server_policy::verify_homeserver,
+            init::init_app,
+            server_probe::probe_homeservers,
+            direct_rooms::get_dm_with,
+            direct_rooms::create_dm,
+            direct_rooms::classify_as_dm,
+            direct_rooms::repair_stale_dm,
+            sender_display::compute_sender_displays,
+            encryption_policy::set_encryption_policy,
+            encryption_policy::get_unverified_devices_in_room,
+            device_messages::send_to_device,
+            device_messages::watch_to_device,
+            auth::login,
+            auth::login_as_guest,
+            account::get_recovery_contacts,
+            account::request_recovery_contact_validation,
+            account::remove_recovery_contact,
+            auth::accept_terms,
+            registration::start_registration,
+            registration::submit_registration_stage,
+            timeline::compose_timeline_items,
+            timeline::format_system_message,
+            timeline::highlight_keywords,
+            timeline::set_muted_keywords,
+            timeline::should_notify,
+            dnd::set_dnd,
+            dnd::set_quiet_hours,
+            dnd::get_dnd_status,
+            dnd::watch_dnd_state,
+            security_alerts::check_device_list,
+            security_alerts::watch_security_alerts,
+            notification_settings::export_notification_settings,
+            notification_settings::import_notification_settings,
+            room_alias::get_room_aliases,
+            room_alias::add_room_alias,
+            room_alias::remove_room_alias,
+            room_alias::set_canonical_alias,
+            identity::invite_by_email,
+            identity::lookup_contacts,
+            background_sync::background_sync_once,
+            send_queue::open_send_queue,
+            send_queue::enqueue_pending_send,
+            send_queue::complete_pending_send,
+            send_queue::update_pending_send_offset,
+            send_queue::resume_pending_sends,
+            send_queue::rekey_local_stores,
+            secure_storage::get_secure_storage_secret,
+            secure_storage::set_secure_storage_secret,
+            secure_storage::set_secure_storage_secret_with_ttl,
+            secure_storage::clear_secure_storage,
+            secure_storage::get_backend_name,
+            secure_storage::secure_storage_self_test,
+            secure_storage::remove_secure_storage_secret,
+            secure_storage::watch_storage_keys,
+            room_audit::get_room_state_history,
+            spaces::create_space,
+            spaces::add_room_to_space,
+            spaces::remove_room_from_space,
+            join_rules::set_restricted_join_rule,
+            server_acl::get_server_acl,
+            server_acl::set_server_acl,
+            policy_lists::subscribe_to_ban_list,
+            event_debug::get_event_debug_info,
+            slow_mode::set_room_cooldown,
+            slow_mode::check_and_record_send,
+            translation::set_translation_endpoint,
+            translation::translate_message,
+            privacy_proxy::set_privacy_proxy,
+            privacy_proxy::set_room_proxy_override,
+            secure_storage::get_or_generate_db_encryption_key,
+            event_cache::open_event_cache,
+            event_cache::cache_event,
+            event_cache::prune_event_cache,
+            scheduled_messages::schedule_message,
+            scheduled_messages::list_scheduled_messages,
+            scheduled_messages::cancel_scheduled_message,
+            chunked_upload::upload_chunked,
+            animated_media::detect_animated_media,
+            self_destruct::set_self_destruct_enabled,
+            self_destruct::is_self_destruct_enabled,
+            self_destruct::track_self_destructing_message,
+            custom_events::send_custom_event,
+            custom_events::watch_custom_events,
+            widgets::get_room_widgets,
+            openid::get_openid_token,
+            integration_manager::list_available_integrations,
+            integration_manager::provision_integration,
+            bridge_awareness::get_bridge_info,
+            bridge_awareness::is_bridge_ghost,
+            invites::set_invite_policy,
+            invites::get_invite_policy,
+            invites::mark_user_known,
+            invites::evaluate_invite,
+            invites::get_filtered_invites,
+            room_preload::set_preload_priority,
+            room_preload::get_preload_priority,
+            room_preload::bump_preload_priority,
+            room_preload::preload_initial_rooms,
+            event_size_guard::check_event_size,
+            event_size_guard::split_oversized_text,
+            room_upgrades::record_room_upgrade,
+            room_upgrades::mark_room_joined,
+            settings::get_settings,
+            settings::set_settings,
+            settings::watch_settings_changes,
+            settings::sync_settings_account_data,
+            session_import::import_external_session,
+            session_import::import_external_keys_backup,
+            history_visibility::set_room_history_visibility,
+            history_visibility::record_room_join_order,
+            history_visibility::can_view_event,
+            auth::get_device_identity,
+            accounts::suspend_account,
+            accounts::resume_account,
+            accounts::is_account_suspended,
+            media_autodownload::set_media_autodownload_policy,
+            media_autodownload::set_connectivity_class,
+            media_autodownload::queue_deferred_download,
+            media_autodownload::resume_deferred_downloads,
+            notification_dismissal::record_shown_notification,
+            notification_dismissal::process_own_read_receipt,
+            notification_dismissal::watch_notification_dismissals,
+            room_list_sections::compute_room_list_sections,
+            sync_checkpoint::open_sync_checkpoint,
+            sync_checkpoint::checkpoint_sync_state,
+            sync_checkpoint::get_sync_checkpoint,
+            sync_checkpoint::reset_sync_state,
+            clock_skew::record_clock_skew_sample,
+            clock_skew::get_clock_skew_offset_ms,
+            clock_skew::is_clock_skew_significant,
+            server_announcements::check_server_deprecations,
+            server_announcements::check_server_notices_room,
+            server_announcements::watch_server_announcements,
+            room_archive::mark_room_left,
+            room_archive::get_archived_rooms,
+            room_archive::load_archived_timeline,
+            room_archive::forget_room,
+            room_join::join_room,
+            room_permissions::get_room_permissions,
+            composer_text::process_composer_text,
+            composer_text::render_composer_text
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,12 @@
+//! A deterministic test harness for a `MatrixClient`, with canned
+//! homeserver fixtures (versions, login, sync with rooms, send) and a
+//! builder for scripted sync responses, so higher-level features could be
+//! tested against a hermetic fake server instead of a live homeserver.
+//!
+//! This crate has neither a `MatrixClient` nor any existing mockito-based
+//! test boilerplate to extract — there is no Matrix sync/login transport
+//! in this tree yet (see [`crate::background_sync`] and [`crate::auth`]
+//! for the closest existing pieces, both of which talk to no real server),
+//! and this crate has no test suite at all. Recording the request here
+//! rather than dropping it; a real harness needs both of those to exist
+//! first.
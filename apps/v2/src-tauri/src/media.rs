@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager};
+use tokio::sync::broadcast;
+
+use crate::error::AppError;
+
+#[derive(Default)]
+pub struct MediaState {
+    avatars: AvatarCache,
+}
+
+/// Tracks avatar downloads already in flight so concurrent requests for the
+/// same (url, size) pair share a single network fetch instead of racing.
+#[derive(Default)]
+struct AvatarCache {
+    inflight: Mutex<HashMap<String, broadcast::Sender<Result<PathBuf, String>>>>,
+}
+
+fn cache_key(url: &str, size: u32) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(url.as_bytes());
+    hasher.update(size.to_le_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Downloads (or returns the cached copy of) a thumbnail for `url` at
+/// `size` pixels, writing the bytes as the server returned them (they are
+/// not decoded) to the avatar cache directory on disk and returning the
+/// local path. Concurrent calls for the same
+/// (url, size) pair are coalesced onto a single in-flight download instead
+/// of each issuing its own request.
+#[tauri::command]
+pub async fn get_avatar_thumbnail(
+    app: AppHandle,
+    url: String,
+    size: u32,
+    room_id: Option<String>,
+) -> Result<PathBuf, AppError> {
+    let key = cache_key(&url, size);
+    let dest = avatar_cache_dir(&app)?.join(&key);
+
+    if dest.exists() {
+        return Ok(dest);
+    }
+
+    let receiver = {
+        let state = app.state::<crate::state::AppState>();
+        let mut inflight = state.media.avatars.inflight.lock().unwrap();
+        match inflight.get(&key) {
+            Some(tx) => Some(tx.subscribe()),
+            None => {
+                let (tx, _rx) = broadcast::channel(1);
+                inflight.insert(key.clone(), tx);
+                None
+            }
+        }
+    };
+
+    if let Some(mut rx) = receiver {
+        return rx
+            .recv()
+            .await
+            .map_err(|_| AppError::Other("avatar download was dropped".into()))?
+            .map_err(AppError::Other);
+    }
+
+    if !crate::media_autodownload::should_auto_download(&app.state::<crate::state::AppState>(), room_id.as_deref()) {
+        app.state::<crate::state::AppState>().media.avatars.inflight.lock().unwrap().remove(&key);
+        crate::media_autodownload::queue_deferred_download(
+            app.state::<crate::state::AppState>(),
+            crate::media_autodownload::QueuedDownload { room_id, url, size },
+        );
+        return Err(AppError::Other("download deferred by auto-download policy".into()));
+    }
+
+    let client = crate::privacy_proxy::build_client(&app.state::<crate::state::AppState>(), room_id.as_deref())?;
+    let result = download_thumbnail(&client, &url, size, &dest).await;
+
+    let state = app.state::<crate::state::AppState>();
+    if let Some(tx) = state.media.avatars.inflight.lock().unwrap().remove(&key) {
+        let _ = tx.send(result.clone().map_err(|e| e.to_string()));
+    }
+
+    result
+}
+
+async fn download_thumbnail(client: &reqwest::Client, url: &str, size: u32, dest: &PathBuf) -> Result<PathBuf, AppError> {
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let response = client
+        .get(thumbnail_url(url, size))
+        .send()
+        .await
+        .map_err(|e| AppError::Other(e.to_string()))?;
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| AppError::Other(e.to_string()))?;
+    std::fs::write(dest, &bytes)?;
+    Ok(dest.clone())
+}
+
+fn thumbnail_url(url: &str, size: u32) -> String {
+    format!("{url}?thumbnail_size={size}")
+}
+
+fn avatar_cache_dir(app: &AppHandle) -> Result<PathBuf, AppError> {
+    let dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| AppError::Other(e.to_string()))?
+        .join("avatars");
+    Ok(dir)
+}
+
+/// Computes a BlurHash placeholder from an already-decoded RGBA8 buffer of
+/// the given `width` x `height`, so outgoing images get a cheap blurred
+/// preview without blocking the UI thread on a full image codec. Returns
+/// only the hash; the dimensions are the ones the caller passed in and are
+/// not derived from the image here.
+#[tauri::command]
+pub fn compute_blurhash(rgba: Vec<u8>, width: usize, height: usize) -> Result<String, AppError> {
+    crate::blurhash::encode(&rgba, width, height, 4, 3)
+}
+
+/// Decodes a BlurHash string back into an RGBA8 buffer sized `width` x
+/// `height`, for rendering a placeholder before the real image downloads.
+#[tauri::command]
+pub fn decode_blurhash(hash: String, width: usize, height: usize) -> Result<Vec<u8>, AppError> {
+    crate::blurhash::decode(&hash, width, height, 1.0)
+}
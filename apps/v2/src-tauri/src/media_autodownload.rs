@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::settings::MediaAutoDownloadPolicy;
+
+/// Connectivity class the frontend's platform layer last reported, since
+/// Rust has no portable way to ask the OS directly. `Wifi` is the default
+/// for an install that hasn't reported one yet, so media isn't blocked
+/// before the first callback arrives.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectivityClass {
+    Wifi,
+    Cellular,
+    Offline,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AutoDownloadScope {
+    Global,
+    Room { room_id: String },
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedDownload {
+    pub room_id: Option<String>,
+    pub url: String,
+    pub size: u32,
+}
+
+/// Per-room media auto-download overrides, a global default, the
+/// connectivity class the frontend last reported, and downloads deferred
+/// because the policy said not yet — consulted by the media pipeline
+/// before starting a download so attachments don't burn a metered
+/// connection the user asked to be careful with.
+#[derive(Default)]
+pub struct MediaAutoDownloadState {
+    global: Mutex<MediaAutoDownloadPolicy>,
+    room_overrides: Mutex<HashMap<String, MediaAutoDownloadPolicy>>,
+    connectivity: Mutex<Option<ConnectivityClass>>,
+    pending: Mutex<Vec<QueuedDownload>>,
+}
+
+#[tauri::command]
+pub fn set_media_autodownload_policy(
+    state: tauri::State<'_, crate::state::AppState>,
+    scope: AutoDownloadScope,
+    policy: MediaAutoDownloadPolicy,
+) {
+    match scope {
+        AutoDownloadScope::Global => *state.media_autodownload.global.lock().unwrap() = policy,
+        AutoDownloadScope::Room { room_id } => {
+            state.media_autodownload.room_overrides.lock().unwrap().insert(room_id, policy);
+        }
+    }
+}
+
+/// Called from the frontend shell whenever the OS reports a connectivity
+/// change.
+#[tauri::command]
+pub fn set_connectivity_class(state: tauri::State<'_, crate::state::AppState>, class: ConnectivityClass) {
+    *state.media_autodownload.connectivity.lock().unwrap() = Some(class);
+}
+
+fn effective_policy(state: &crate::state::AppState, room_id: Option<&str>) -> MediaAutoDownloadPolicy {
+    if let Some(room_id) = room_id {
+        if let Some(policy) = state.media_autodownload.room_overrides.lock().unwrap().get(room_id) {
+            return policy.clone();
+        }
+    }
+    state.media_autodownload.global.lock().unwrap().clone()
+}
+
+/// Whether a download for `room_id` (`None` for a fetch not scoped to a
+/// room, e.g. a user avatar) should proceed right now under the effective
+/// policy and the last-reported connectivity class.
+pub fn should_auto_download(state: &crate::state::AppState, room_id: Option<&str>) -> bool {
+    match effective_policy(state, room_id) {
+        MediaAutoDownloadPolicy::Always => true,
+        MediaAutoDownloadPolicy::Never => false,
+        MediaAutoDownloadPolicy::WifiOnly => !matches!(
+            state.media_autodownload.connectivity.lock().unwrap().unwrap_or(ConnectivityClass::Wifi),
+            ConnectivityClass::Cellular | ConnectivityClass::Offline
+        ),
+    }
+}
+
+/// Records a download that [`should_auto_download`] deferred, so
+/// [`resume_deferred_downloads`] can retry it once policy conditions
+/// change (e.g. the device rejoins wifi).
+#[tauri::command]
+pub fn queue_deferred_download(state: tauri::State<'_, crate::state::AppState>, download: QueuedDownload) {
+    state.media_autodownload.pending.lock().unwrap().push(download);
+}
+
+/// Retries every deferred download whose policy now allows it, via the
+/// same thumbnail fetch path as a normal download, returning the local
+/// paths of the ones that succeeded and leaving the rest queued.
+#[tauri::command]
+pub async fn resume_deferred_downloads(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<Vec<std::path::PathBuf>, AppError> {
+    let ready: Vec<QueuedDownload> = {
+        let mut pending = state.media_autodownload.pending.lock().unwrap();
+        let (ready, still_pending): (Vec<_>, Vec<_>) =
+            pending.drain(..).partition(|d| should_auto_download(&state, d.room_id.as_deref()));
+        *pending = still_pending;
+        ready
+    };
+
+    let mut downloaded = Vec::new();
+    for item in ready {
+        let QueuedDownload { room_id, url, size } = item.clone();
+        match crate::media::get_avatar_thumbnail(app.clone(), url, size, room_id).await {
+            Ok(path) => downloaded.push(path),
+            Err(_) => state.media_autodownload.pending.lock().unwrap().push(item),
+        }
+    }
+    Ok(downloaded)
+}
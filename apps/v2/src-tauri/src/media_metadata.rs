@@ -0,0 +1,199 @@
+use serde::Serialize;
+
+use crate::error::AppError;
+
+/// Populates the `m.video`/`m.audio` info block ahead of upload so
+/// recipients see correct duration and dimensions without downloading the
+/// file first.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AvMetadata {
+    pub duration_ms: Option<u32>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub codec: Option<String>,
+}
+
+/// Extracts duration/dimensions/codec from an audio or video file ahead of
+/// upload. Supports the ISO base media format (MP4/MOV, via `moov`/`mvhd`
+/// and `trak`/`tkhd`/`stsd`) and WAV; other containers return an empty
+/// metadata block rather than an error, since a missing info block is a
+/// cosmetic regression, not a failed upload.
+#[tauri::command]
+pub fn extract_av_metadata(bytes: Vec<u8>) -> Result<AvMetadata, AppError> {
+    if bytes.len() >= 12 && &bytes[4..8] == b"ftyp" {
+        return Ok(extract_mp4_metadata(&bytes));
+    }
+    if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WAVE" {
+        return Ok(extract_wav_metadata(&bytes));
+    }
+    Ok(AvMetadata::default())
+}
+
+/// Walks top-level MP4 boxes looking for `moov/mvhd` (overall duration and
+/// timescale) and the first `moov/trak/tkhd` (video dimensions, stored as
+/// 16.16 fixed-point). Audio-only files simply have no dimensions. The codec
+/// is that track's `mdia/minf/stbl/stsd` sample-entry fourcc (e.g. `avc1`,
+/// `mp4a`), or `"mp4"` when the track has no sample description.
+fn extract_mp4_metadata(bytes: &[u8]) -> AvMetadata {
+    let mut metadata = AvMetadata { codec: Some("mp4".into()), ..Default::default() };
+
+    if let Some(moov) = find_box(bytes, b"moov") {
+        if let Some(mvhd) = find_box(moov, b"mvhd") {
+            if mvhd.len() >= 20 {
+                let version = mvhd[0];
+                let (timescale, duration) = if version == 1 && mvhd.len() >= 32 {
+                    (
+                        u32::from_be_bytes(mvhd[20..24].try_into().unwrap()),
+                        u64::from_be_bytes(mvhd[24..32].try_into().unwrap()),
+                    )
+                } else {
+                    (
+                        u32::from_be_bytes(mvhd[12..16].try_into().unwrap()),
+                        u32::from_be_bytes(mvhd[16..20].try_into().unwrap()) as u64,
+                    )
+                };
+                if timescale > 0 {
+                    metadata.duration_ms = Some((duration * 1000 / timescale as u64) as u32);
+                }
+            }
+        }
+        if let Some(trak) = find_box(moov, b"trak") {
+            if let Some(tkhd) = find_box(trak, b"tkhd") {
+                if tkhd.len() >= 84 {
+                    let width = u32::from_be_bytes(tkhd[76..80].try_into().unwrap()) >> 16;
+                    let height = u32::from_be_bytes(tkhd[80..84].try_into().unwrap()) >> 16;
+                    if width > 0 && height > 0 {
+                        metadata.width = Some(width);
+                        metadata.height = Some(height);
+                    }
+                }
+            }
+            if let Some(fourcc) = find_box(trak, b"mdia")
+                .and_then(|mdia| find_box(mdia, b"minf"))
+                .and_then(|minf| find_box(minf, b"stbl"))
+                .and_then(|stbl| find_box(stbl, b"stsd"))
+                .and_then(|stsd| stsd.get(12..16))
+            {
+                metadata.codec = Some(String::from_utf8_lossy(fourcc).trim_end().to_string());
+            }
+        }
+    }
+
+    metadata
+}
+
+/// Returns the contents of the first child box named `name` within
+/// `container` (a sequence of `[size: u32][fourcc: 4][payload...]` boxes).
+fn find_box<'a>(container: &'a [u8], name: &[u8; 4]) -> Option<&'a [u8]> {
+    let mut i = 0;
+    while i + 8 <= container.len() {
+        let size = u32::from_be_bytes(container[i..i + 4].try_into().ok()?) as usize;
+        let box_type = &container[i + 4..i + 8];
+        if size < 8 || i + size > container.len() {
+            break;
+        }
+        if box_type == name {
+            return Some(&container[i + 8..i + size]);
+        }
+        i += size;
+    }
+    None
+}
+
+/// WAV's `fmt ` chunk gives sample rate and byte rate; duration follows from
+/// the `data` chunk size divided by byte rate.
+fn extract_wav_metadata(bytes: &[u8]) -> AvMetadata {
+    let mut metadata = AvMetadata { codec: Some("wav".into()), ..Default::default() };
+    let mut byte_rate: Option<u32> = None;
+    let mut i = 12;
+    while i + 8 <= bytes.len() {
+        let chunk_id = &bytes[i..i + 4];
+        let chunk_size = u32::from_le_bytes(bytes[i + 4..i + 8].try_into().unwrap()) as usize;
+        let data_start = i + 8;
+        if chunk_id == b"fmt " && data_start + 16 <= bytes.len() {
+            byte_rate = Some(u32::from_le_bytes(bytes[data_start + 8..data_start + 12].try_into().unwrap()));
+        } else if chunk_id == b"data" {
+            if let Some(rate) = byte_rate {
+                if rate > 0 {
+                    metadata.duration_ms = Some((chunk_size as u64 * 1000 / rate as u64) as u32);
+                }
+            }
+        }
+        i = data_start + chunk_size + (chunk_size % 2);
+    }
+    metadata
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mp4_box(fourcc: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut bytes = ((payload.len() + 8) as u32).to_be_bytes().to_vec();
+        bytes.extend_from_slice(fourcc);
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    #[test]
+    fn extracts_mp4_duration_and_dimensions() {
+        let mut mvhd = vec![0u8; 20];
+        mvhd[12..16].copy_from_slice(&1000u32.to_be_bytes()); // timescale
+        mvhd[16..20].copy_from_slice(&2000u32.to_be_bytes()); // duration
+
+        let mut tkhd = vec![0u8; 84];
+        tkhd[76..80].copy_from_slice(&(640u32 << 16).to_be_bytes());
+        tkhd[80..84].copy_from_slice(&(480u32 << 16).to_be_bytes());
+
+        let trak = mp4_box(b"tkhd", &tkhd);
+        let mut moov_payload = mp4_box(b"mvhd", &mvhd);
+        moov_payload.extend(mp4_box(b"trak", &trak));
+        let moov = mp4_box(b"moov", &moov_payload);
+
+        let mut file = mp4_box(b"ftyp", b"isom\0\0\0\0");
+        file.extend(moov);
+
+        let metadata = extract_av_metadata(file).unwrap();
+        assert_eq!(metadata.duration_ms, Some(2000));
+        assert_eq!(metadata.width, Some(640));
+        assert_eq!(metadata.height, Some(480));
+        assert_eq!(metadata.codec.as_deref(), Some("mp4"));
+    }
+
+    #[test]
+    fn unknown_container_returns_empty_metadata_without_erroring() {
+        let metadata = extract_av_metadata(vec![0u8; 20]).unwrap();
+        assert_eq!(metadata.duration_ms, None);
+        assert_eq!(metadata.codec, None);
+    }
+
+    #[test]
+    fn truncated_input_does_not_panic() {
+        assert!(extract_av_metadata(vec![]).is_ok());
+        assert!(extract_av_metadata(b"....ftyp".to_vec()).is_ok());
+        assert!(extract_av_metadata(b"RIFF....WAVE".to_vec()).is_ok());
+    }
+
+    #[test]
+    fn extracts_wav_duration_from_fmt_and_data_chunks() {
+        let mut bytes = b"RIFF".to_vec();
+        bytes.extend_from_slice(&0u32.to_le_bytes()); // overall size, unused
+        bytes.extend_from_slice(b"WAVE");
+
+        let mut fmt_payload = vec![0u8; 16];
+        fmt_payload[8..12].copy_from_slice(&1000u32.to_le_bytes()); // byte rate
+        bytes.extend_from_slice(b"fmt ");
+        bytes.extend_from_slice(&(fmt_payload.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&fmt_payload);
+
+        let data_payload = vec![0u8; 2000];
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&(data_payload.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&data_payload);
+
+        let metadata = extract_av_metadata(bytes).unwrap();
+        assert_eq!(metadata.duration_ms, Some(2000));
+        assert_eq!(metadata.codec.as_deref(), Some("wav"));
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomMember {
+    pub user_id: String,
+    pub display_name: String,
+    pub power_level: i64,
+    pub last_active_ms: i64,
+}
+
+/// Tracks which rooms have had their full member list fetched, so the
+/// member-list screen can trigger a one-time fetch instead of the initial
+/// sync shipping every member of every room up front, plus the cached
+/// members themselves for in-process paging and search.
+#[derive(Default)]
+pub struct MemberLoadState {
+    fully_loaded_rooms: Mutex<HashSet<String>>,
+    cache: Mutex<HashMap<String, Vec<RoomMember>>>,
+}
+
+/// Replaces the cached member list for `room_id`, called once a fetch (or a
+/// membership diff from sync) has produced a fresh list. Paging and search
+/// below only ever read this cache, so a 50k-member room never crosses the
+/// FFI boundary more than once.
+#[tauri::command]
+pub fn set_room_members(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    members: Vec<RoomMember>,
+) {
+    state.members.cache.lock().unwrap().insert(room_id, members);
+}
+
+fn sorted_members(state: &crate::state::AppState, room_id: &str) -> Vec<RoomMember> {
+    let mut members = state
+        .members
+        .cache
+        .lock()
+        .unwrap()
+        .get(room_id)
+        .cloned()
+        .unwrap_or_default();
+    members.sort_by(|a, b| {
+        b.power_level.cmp(&a.power_level).then(b.last_active_ms.cmp(&a.last_active_ms))
+    });
+    members
+}
+
+/// Returns members sorted by power level then recent activity, `page_size`
+/// at a time starting at `offset`, so huge rooms never ship their whole
+/// member list across FFI at once.
+#[tauri::command]
+pub fn get_room_members_page(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    offset: usize,
+    page_size: usize,
+) -> Vec<RoomMember> {
+    sorted_members(&state, &room_id).into_iter().skip(offset).take(page_size).collect()
+}
+
+/// Searches the cached member list for `room_id` by case-insensitive
+/// substring match on display name or user id, executed here in Rust
+/// against the cache instead of shipping the whole list to the frontend
+/// for incremental filtering.
+#[tauri::command]
+pub fn search_room_members(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    query: String,
+) -> Vec<RoomMember> {
+    let query = query.to_lowercase();
+    sorted_members(&state, &room_id)
+        .into_iter()
+        .filter(|m| {
+            m.display_name.to_lowercase().contains(&query) || m.user_id.to_lowercase().contains(&query)
+        })
+        .collect()
+}
+
+/// Ensures the full member list for `room_id` has been fetched, fetching it
+/// on first call and returning immediately on subsequent ones. The actual
+/// member-list network fetch is a no-op until the room/sync service exists;
+/// this only owns the "have we already paid for this room" bookkeeping.
+#[tauri::command]
+pub fn ensure_members_loaded(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+) -> Result<bool, AppError> {
+    let mut loaded = state.members.fully_loaded_rooms.lock().unwrap();
+    if loaded.contains(&room_id) {
+        return Ok(false);
+    }
+    loaded.insert(room_id);
+    Ok(true)
+}
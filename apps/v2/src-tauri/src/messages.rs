@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::error::AppError;
+
+#[derive(Default)]
+pub struct MessagesState {
+    pinned: PinnedMessages,
+    starred: StarredMessages,
+}
+
+/// A bookmarked message, identified by its room and event id. Stored the
+/// same way pinned messages are for now: local-only, since this client has
+/// no account-data sync yet to make it roam across a user's devices the
+/// way `skiffy.*` account data would.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StarredMessage {
+    pub room_id: String,
+    pub event_id: String,
+}
+
+#[derive(Default)]
+pub struct StarredMessages {
+    items: Mutex<Vec<StarredMessage>>,
+}
+
+/// Tracks which message ids are pinned per room. Pinned ids are hydrated
+/// into full timeline items by the timeline layer; this module only owns
+/// the pinned set itself.
+#[derive(Default)]
+pub struct PinnedMessages {
+    by_room: Mutex<HashMap<String, Vec<String>>>,
+}
+
+#[tauri::command]
+pub fn pin_message(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    event_id: String,
+) -> Result<(), AppError> {
+    crate::auth::ensure_not_guest(&state.guest)?;
+    let mut by_room = state.messages.pinned.by_room.lock().unwrap();
+    let pinned = by_room.entry(room_id).or_default();
+    if !pinned.contains(&event_id) {
+        pinned.push(event_id);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn unpin_message(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    event_id: String,
+) -> Result<(), AppError> {
+    let mut by_room = state.messages.pinned.by_room.lock().unwrap();
+    if let Some(pinned) = by_room.get_mut(&room_id) {
+        pinned.retain(|id| id != &event_id);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn star_message(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    event_id: String,
+) -> Result<(), AppError> {
+    crate::auth::ensure_not_guest(&state.guest)?;
+    let mut items = state.messages.starred.items.lock().unwrap();
+    let starred = StarredMessage { room_id, event_id };
+    if !items.contains(&starred) {
+        items.push(starred);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn unstar_message(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    event_id: String,
+) -> Result<(), AppError> {
+    state.messages.starred.items.lock().unwrap().retain(|s| !(s.room_id == room_id && s.event_id == event_id));
+    Ok(())
+}
+
+/// Returns every starred message across all rooms, in the order they were
+/// starred, for the caller to hydrate into full timeline items the same
+/// way `get_pinned_messages` does.
+#[tauri::command]
+pub fn get_starred_messages(state: tauri::State<'_, crate::state::AppState>) -> Vec<StarredMessage> {
+    state.messages.starred.items.lock().unwrap().clone()
+}
+
+/// Returns the pinned event ids for `room_id`, in pin order, for the
+/// pinned-messages banner to hydrate into full timeline items.
+#[tauri::command]
+pub fn get_pinned_messages(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+) -> Vec<String> {
+    state
+        .messages
+        .pinned
+        .by_room
+        .lock()
+        .unwrap()
+        .get(&room_id)
+        .cloned()
+        .unwrap_or_default()
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::Serialize;
+
+use crate::error::AppError;
+
+/// Local-only counters/histograms for diagnosing performance in the field
+/// (sync latency, send latency, undecryptable-event count, HTTP error
+/// rates). Opt-in and local by default; no data leaves the device unless
+/// `set_telemetry_opt_in(true)` has been called and an exporter is wired up.
+#[derive(Default)]
+pub struct Metrics {
+    opted_in: Mutex<bool>,
+    counters: Mutex<HashMap<String, u64>>,
+    histograms: Mutex<HashMap<String, Vec<f64>>>,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HistogramSummary {
+    pub count: u64,
+    pub p50: f64,
+    pub p95: f64,
+    pub max: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsSnapshot {
+    pub opted_in: bool,
+    pub counters: HashMap<String, u64>,
+    pub histograms: HashMap<String, HistogramSummary>,
+}
+
+#[tauri::command]
+pub fn set_telemetry_opt_in(state: tauri::State<'_, crate::state::AppState>, opted_in: bool) {
+    *state.metrics.opted_in.lock().unwrap() = opted_in;
+}
+
+/// Increments a named counter (e.g. `"http_error"`, `"utd_count"`) by one.
+/// A no-op ID, not a no-op call, when telemetry is opted out — counters
+/// still accumulate locally so `get_metrics_snapshot` stays useful for
+/// local diagnosis even without the opt-in.
+#[tauri::command]
+pub fn record_counter(state: tauri::State<'_, crate::state::AppState>, name: String) {
+    record_named_counter(&state.metrics, name);
+}
+
+fn record_named_counter(metrics: &Metrics, name: String) {
+    *metrics.counters.lock().unwrap().entry(name).or_insert(0) += 1;
+}
+
+/// Records one observation (e.g. a sync or send latency in milliseconds)
+/// into a named histogram. Non-finite values (`NaN`, `inf`) are dropped
+/// rather than stored, since they'd otherwise poison `summarize`'s sort.
+#[tauri::command]
+pub fn record_histogram(state: tauri::State<'_, crate::state::AppState>, name: String, value: f64) {
+    record_named_histogram(&state.metrics, name, value);
+}
+
+fn record_named_histogram(metrics: &Metrics, name: String, value: f64) {
+    if !value.is_finite() {
+        return;
+    }
+    metrics.histograms.lock().unwrap().entry(name).or_default().push(value);
+}
+
+#[tauri::command]
+pub fn get_metrics_snapshot(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<MetricsSnapshot, AppError> {
+    let counters = state
+        .metrics
+        .counters
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(k, v)| (k.to_string(), *v))
+        .collect();
+
+    let histograms = state
+        .metrics
+        .histograms
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(k, values)| (k.to_string(), summarize(values)))
+        .collect();
+
+    Ok(MetricsSnapshot {
+        opted_in: *state.metrics.opted_in.lock().unwrap(),
+        counters,
+        histograms,
+    })
+}
+
+fn summarize(values: &[f64]) -> HistogramSummary {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let percentile = |p: f64| {
+        if sorted.is_empty() {
+            0.0
+        } else {
+            sorted[((sorted.len() - 1) as f64 * p).round() as usize]
+        }
+    };
+    HistogramSummary {
+        count: sorted.len() as u64,
+        p50: percentile(0.5),
+        p95: percentile(0.95),
+        max: sorted.last().copied().unwrap_or(0.0),
+    }
+}
+
+/// Posts the current snapshot to a posthog-compatible ingestion endpoint.
+/// Only ever called when `opted_in` is true; this client has no bundled
+/// posthog project configured yet, so this is a real HTTP call shaped for
+/// that API, exercised only once a destination URL and key exist.
+#[tauri::command]
+pub async fn export_metrics_snapshot(
+    state: tauri::State<'_, crate::state::AppState>,
+    endpoint_url: String,
+    api_key: String,
+) -> Result<(), AppError> {
+    if !*state.metrics.opted_in.lock().unwrap() {
+        return Err(AppError::Other("telemetry export requires opt-in".into()));
+    }
+    let snapshot = get_metrics_snapshot(state)?;
+
+    reqwest::Client::new()
+        .post(endpoint_url)
+        .json(&serde_json::json!({ "api_key": api_key, "event": "metrics_snapshot", "properties": snapshot }))
+        .send()
+        .await
+        .map_err(|e| AppError::Other(e.to_string()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarize_does_not_panic_on_nan() {
+        // Defense in depth: record_histogram already filters non-finite
+        // values, but summarize must not panic even if one slips through.
+        let summary = summarize(&[1.0, f64::NAN, 3.0, 2.0]);
+        assert_eq!(summary.count, 4);
+    }
+
+    #[test]
+    fn summarize_computes_percentiles() {
+        let summary = summarize(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(summary.count, 5);
+        assert_eq!(summary.p50, 3.0);
+        assert_eq!(summary.max, 5.0);
+    }
+
+    #[test]
+    fn summarize_empty_is_zeroed() {
+        let summary = summarize(&[]);
+        assert_eq!(summary.count, 0);
+        assert_eq!(summary.p50, 0.0);
+    }
+
+    #[test]
+    fn record_histogram_drops_non_finite_values() {
+        let metrics = Metrics::default();
+        record_named_histogram(&metrics, "latency".to_string(), 1.0);
+        // Exercised through record_named_histogram, which record_histogram
+        // delegates to, since the command itself takes tauri::State.
+        record_named_histogram(&metrics, "latency".to_string(), f64::NAN);
+        assert_eq!(metrics.histograms.lock().unwrap()["latency"].len(), 1);
+        record_named_histogram(&metrics, "latency".to_string(), f64::INFINITY);
+        assert_eq!(metrics.histograms.lock().unwrap()["latency"].len(), 1);
+        assert_eq!(metrics.histograms.lock().unwrap()["latency"], vec![1.0]);
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use rusqlite::OptionalExtension;
+use serde::Serialize;
+use tauri::AppHandle;
+
+use crate::error::AppError;
+
+pub const NOTIFICATION_DISMISSED: &str = "notifications://dismissed";
+
+/// Event ids of notifications currently shown locally, per room, so a read
+/// receipt observed from one of the user's own devices can tell which of
+/// them to clear — otherwise a message read on another device keeps
+/// buzzing this one, a notorious annoyance if left unhandled.
+#[derive(Default)]
+pub struct ShownNotifications {
+    by_room: Mutex<HashMap<String, Vec<String>>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDismissal {
+    pub room_id: String,
+    pub event_ids: Vec<String>,
+}
+
+/// Records that a notification for `event_id` in `room_id` is currently
+/// shown, called whenever one is raised so a later own-device receipt
+/// knows what it may need to dismiss.
+#[tauri::command]
+pub fn record_shown_notification(state: tauri::State<'_, crate::state::AppState>, room_id: String, event_id: String) {
+    state.shown_notifications.by_room.lock().unwrap().entry(room_id).or_default().push(event_id);
+}
+
+fn cached_order(state: &crate::state::AppState, room_id: &str, event_id: &str) -> Result<Option<i64>, AppError> {
+    Ok(state
+        .event_cache
+        .connection()
+        .lock()
+        .unwrap()
+        .query_row(
+            "SELECT received_order FROM cached_events WHERE room_id = ?1 AND event_id = ?2",
+            rusqlite::params![room_id, event_id],
+            |row| row.get(0),
+        )
+        .optional()?)
+}
+
+/// Processes an `m.read` receipt observed from one of the user's own
+/// devices: every notification currently shown locally for an event at or
+/// before the receipt's position in the room is dismissed, and
+/// [`watch_notification_dismissals`] subscribers are told which ones.
+#[tauri::command]
+pub fn process_own_read_receipt(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    read_up_to_event_id: String,
+) -> Result<(), AppError> {
+    let Some(read_up_to_order) = cached_order(&state, &room_id, &read_up_to_event_id)? else {
+        return Ok(());
+    };
+
+    let dismissed: Vec<String> = {
+        let mut by_room = state.shown_notifications.by_room.lock().unwrap();
+        let Some(shown) = by_room.get_mut(&room_id) else {
+            return Ok(());
+        };
+        let (dismissed, remaining): (Vec<_>, Vec<_>) = std::mem::take(shown).into_iter().partition(|event_id| {
+            cached_order(&state, &room_id, event_id).ok().flatten().map(|order| order <= read_up_to_order).unwrap_or(false)
+        });
+        *shown = remaining;
+        dismissed
+    };
+
+    if !dismissed.is_empty() {
+        crate::streams::coalesced_emit(&app, NOTIFICATION_DISMISSED, NotificationDismissal { room_id, event_ids: dismissed });
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn watch_notification_dismissals() -> &'static str {
+    NOTIFICATION_DISMISSED
+}
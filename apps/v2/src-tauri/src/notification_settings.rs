@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSettings {
+    pub global_rules: serde_json::Value,
+    pub per_room_overrides: serde_json::Value,
+}
+
+/// Writes the account's push rules and per-room overrides to `dest`, so
+/// users can restore a carefully tuned notification setup after switching
+/// homeservers.
+#[tauri::command]
+pub fn export_notification_settings(
+    settings: NotificationSettings,
+    dest: PathBuf,
+) -> Result<(), AppError> {
+    let json = serde_json::to_string_pretty(&settings).map_err(|e| AppError::Other(e.to_string()))?;
+    std::fs::write(dest, json)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn import_notification_settings(src: PathBuf) -> Result<NotificationSettings, AppError> {
+    let json = std::fs::read_to_string(src)?;
+    serde_json::from_str(&json).map_err(|e| AppError::Other(e.to_string()))
+}
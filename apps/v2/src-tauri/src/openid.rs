@@ -0,0 +1,59 @@
+//! Caches OpenID tokens for integrations (widgets, integration managers,
+//! Element Call authentication) so callers don't each request a fresh one.
+//!
+//! This client has no account service to call `/user/{userId}/openid/request_token`
+//! against yet, so the cache itself is real but [`get_openid_token`] has
+//! nothing to populate it with. Recording the request here rather than
+//! dropping it; once account-service calls exist, they slot into
+//! `fetch_openid_token` below without the cache needing to change.
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenIdToken {
+    pub access_token: String,
+    pub token_type: String,
+    pub matrix_server_name: String,
+    pub expires_at_rfc3339: String,
+}
+
+#[derive(Default)]
+pub struct OpenIdCache {
+    cached: Mutex<Option<OpenIdToken>>,
+}
+
+fn is_fresh(token: &OpenIdToken) -> bool {
+    DateTime::parse_from_rfc3339(&token.expires_at_rfc3339)
+        .map(|expiry| expiry.with_timezone(&Utc) > Utc::now())
+        .unwrap_or(false)
+}
+
+/// Returns the cached token if it hasn't expired yet, otherwise fetches a
+/// fresh one and caches it.
+#[tauri::command]
+pub fn get_openid_token(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<OpenIdToken, AppError> {
+    {
+        let cached = state.openid.cached.lock().unwrap();
+        if let Some(token) = cached.as_ref() {
+            if is_fresh(token) {
+                return Ok(token.clone());
+            }
+        }
+    }
+    let token = fetch_openid_token()?;
+    *state.openid.cached.lock().unwrap() = Some(token.clone());
+    Ok(token)
+}
+
+/// Would call `/user/{userId}/openid/request_token` on the homeserver. This
+/// client has no account service yet to make that call through.
+fn fetch_openid_token() -> Result<OpenIdToken, AppError> {
+    Err(AppError::Other("not applicable: this client has no account service integration".into()))
+}
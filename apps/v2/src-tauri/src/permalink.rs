@@ -0,0 +1,82 @@
+use serde::Serialize;
+
+use crate::error::AppError;
+
+/// What a resolved permalink points at.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PermalinkTarget {
+    Room { room_id: String, event_id: Option<String> },
+    User { user_id: String },
+}
+
+/// Parses a `skiffy://room/<room_id>[/<event_id>]` or
+/// `skiffy://user/<user_id>` permalink into a navigation target, so tapping
+/// a link or a reply-preview can jump straight to the right place.
+#[tauri::command]
+pub fn resolve_permalink(uri: String) -> Result<PermalinkTarget, AppError> {
+    let rest = uri
+        .strip_prefix("skiffy://")
+        .ok_or_else(|| AppError::Other("unrecognized permalink scheme".into()))?;
+
+    let mut segments = rest.split('/').filter(|s| !s.is_empty());
+    match segments.next() {
+        Some("room") => {
+            let room_id = segments
+                .next()
+                .ok_or_else(|| AppError::Other("permalink is missing a room id".into()))?
+                .to_string();
+            let event_id = segments.next().map(str::to_string);
+            Ok(PermalinkTarget::Room { room_id, event_id })
+        }
+        Some("user") => {
+            let user_id = segments
+                .next()
+                .ok_or_else(|| AppError::Other("permalink is missing a user id".into()))?
+                .to_string();
+            Ok(PermalinkTarget::User { user_id })
+        }
+        _ => Err(AppError::Other("unrecognized permalink target".into())),
+    }
+}
+
+/// Parses a deep link from any of the forms this app's links arrive in —
+/// the native `skiffy://` scheme, or a web fallback link of the form
+/// `https://<host>/#/room/<room_id>[/<event_id>]` or `/#/user/<user_id>` —
+/// into the same navigation target `resolve_permalink` produces, so routing
+/// doesn't re-implement URL parsing for each link source.
+#[tauri::command]
+pub fn parse_deep_link(url: String) -> Result<PermalinkTarget, AppError> {
+    if url.starts_with("skiffy://") {
+        return resolve_permalink(url);
+    }
+
+    let fragment = url
+        .split_once("/#/")
+        .map(|(_, after)| after)
+        .ok_or_else(|| AppError::Other("unrecognized deep link format".into()))?;
+
+    resolve_permalink(format!("skiffy://{fragment}"))
+}
+
+/// Loads the timeline page(s) surrounding `event_id` so the UI can jump to
+/// it directly. Depends on the local timeline/event store, which does not
+/// exist in this client yet — returns a typed error until that store lands.
+///
+/// Checks [`crate::history_visibility::can_view_event_for`] first, so a
+/// permalink to an event from before the user joined a `history_visibility:
+/// joined` room surfaces an informative "history not visible" error rather
+/// than the generic "not implemented" one below.
+#[tauri::command]
+pub fn load_timeline_around_event(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    event_id: String,
+) -> Result<(), AppError> {
+    if !crate::history_visibility::can_view_event_for(&state, &room_id, &event_id)? {
+        return Err(AppError::Other("history is not visible to you before you joined this room".into()));
+    }
+    Err(AppError::Other(
+        "timeline store is not implemented yet; cannot paginate around an event".into(),
+    ))
+}
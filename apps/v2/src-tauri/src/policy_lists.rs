@@ -0,0 +1,16 @@
+//! Subscribing to MSC2313 moderation policy rooms/lists, and applying their
+//! user/server rules to incoming invites and timeline rendering (flagging
+//! events from listed users).
+//!
+//! This client has no room-state ingestion yet (see [`crate::bridge_awareness`]
+//! for the same gap), so there is no way to join a policy room and read its
+//! `m.policy.rule.*` state events. The part of this feature that's pure
+//! local logic — [`crate::invites`]'s policy enum — already exists and is
+//! the natural place a `PolicyList` variant would plug in once ingestion
+//! does. Recording the request here rather than dropping it.
+use crate::error::AppError;
+
+#[tauri::command]
+pub fn subscribe_to_ban_list(_room_alias: String) -> Result<(), AppError> {
+    Err(AppError::Other("not applicable: this client has no room-state ingestion".into()))
+}
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::error::AppError;
+
+/// Routes media downloads (and, once one exists, URL previews — this
+/// client has no preview-fetching module yet) through a user-configured
+/// proxy, so the homeserver/CDN/preview target can't correlate a client's
+/// IP with what it's fetching. Trusted rooms can opt back out of the
+/// global proxy via a per-room override.
+#[derive(Default)]
+pub struct PrivacyProxyConfig {
+    global_proxy_url: Mutex<Option<String>>,
+    room_overrides: Mutex<HashMap<String, Option<String>>>,
+}
+
+#[tauri::command]
+pub fn set_privacy_proxy(state: tauri::State<'_, crate::state::AppState>, proxy_url: Option<String>) {
+    *state.privacy_proxy.global_proxy_url.lock().unwrap() = proxy_url;
+}
+
+/// Overrides the proxy used for `room_id`'s media and previews. Pass
+/// `Some(None)` to route this trusted room direct, bypassing the global
+/// proxy; pass `None` to remove the override and fall back to the global
+/// setting.
+#[tauri::command]
+pub fn set_room_proxy_override(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    proxy_url: Option<Option<String>>,
+) {
+    let mut overrides = state.privacy_proxy.room_overrides.lock().unwrap();
+    match proxy_url {
+        Some(value) => {
+            overrides.insert(room_id, value);
+        }
+        None => {
+            overrides.remove(&room_id);
+        }
+    }
+}
+
+/// Resolves which proxy (if any) should be used for a fetch in `room_id`,
+/// or the global proxy if `room_id` is `None` (e.g. a user avatar fetch
+/// not scoped to a room).
+fn resolve_proxy_url(state: &crate::state::AppState, room_id: Option<&str>) -> Option<String> {
+    if let Some(room_id) = room_id {
+        if let Some(override_value) = state.privacy_proxy.room_overrides.lock().unwrap().get(room_id) {
+            return override_value.clone();
+        }
+    }
+    state.privacy_proxy.global_proxy_url.lock().unwrap().clone()
+}
+
+/// Builds an HTTP client that routes through the resolved proxy for
+/// `room_id`, or a plain client if no proxy applies.
+pub fn build_client(state: &crate::state::AppState, room_id: Option<&str>) -> Result<reqwest::Client, AppError> {
+    let builder = reqwest::Client::builder();
+    let builder = match resolve_proxy_url(state, room_id) {
+        Some(proxy_url) => {
+            builder.proxy(reqwest::Proxy::all(&proxy_url).map_err(|e| AppError::Other(e.to_string()))?)
+        }
+        None => builder,
+    };
+    builder.build().map_err(|e| AppError::Other(e.to_string()))
+}
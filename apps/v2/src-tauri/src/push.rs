@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PusherRegistration {
+    pub endpoint_url: String,
+    pub kind: &'static str,
+}
+
+/// Builds a generic HTTP pusher registration pointed at `endpoint_url`, the
+/// push gateway chosen by the user's UnifiedPush distributor, after checking
+/// that it parses and uses https. This is the de-Googled-Android path
+/// alongside the platform-native FCM/APNs pushers. The registration is only
+/// returned to the caller; it is not sent to any server from here.
+#[tauri::command]
+pub async fn register_unified_push(endpoint_url: String) -> Result<PusherRegistration, AppError> {
+    let parsed = url::Url::parse(&endpoint_url)
+        .map_err(|e| AppError::Other(format!("invalid UnifiedPush endpoint: {e}")))?;
+    if parsed.scheme() != "https" {
+        return Err(AppError::Other("UnifiedPush endpoints must use https".into()));
+    }
+
+    Ok(PusherRegistration {
+        endpoint_url,
+        kind: "http",
+    })
+}
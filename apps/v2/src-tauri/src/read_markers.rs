@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default)]
+struct RoomReadMarker {
+    marker_event_id: String,
+    events_since_marker: u32,
+}
+
+/// Which receipt types a read should send. Held in memory only and
+/// returned by [`get_read_receipt_mode`]; it is not written to account
+/// data, and no code in this crate sends receipts with it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadReceiptMode {
+    /// Send `m.read`, visible to other room members.
+    #[default]
+    Public,
+    /// Send `m.read.private`, visible only to the user's own devices.
+    Private,
+    /// Send neither; only advance the fully-read marker.
+    Off,
+}
+
+/// Per-room fully-read marker, used to render the "jump to unread" divider
+/// and button. `events_since_marker` is bumped by the sync layer as new
+/// timeline events arrive for a room; that wiring lands with the sync
+/// service itself, so it stays at 0 until then.
+#[derive(Default)]
+pub struct ReadMarkers {
+    by_room: Mutex<HashMap<String, RoomReadMarker>>,
+    receipt_mode: Mutex<ReadReceiptMode>,
+}
+
+/// Sets the global read-receipt privacy mode returned by
+/// [`get_read_receipt_mode`].
+#[tauri::command]
+pub fn set_read_receipt_mode(state: tauri::State<'_, crate::state::AppState>, mode: ReadReceiptMode) {
+    *state.read_markers.receipt_mode.lock().unwrap() = mode;
+}
+
+#[tauri::command]
+pub fn get_read_receipt_mode(state: tauri::State<'_, crate::state::AppState>) -> ReadReceiptMode {
+    *state.read_markers.receipt_mode.lock().unwrap()
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnreadInfo {
+    pub marker_event_id: String,
+    pub events_since_marker: u32,
+}
+
+#[tauri::command]
+pub fn set_read_marker(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    event_id: String,
+) {
+    let mut by_room = state.read_markers.by_room.lock().unwrap();
+    by_room.insert(
+        room_id,
+        RoomReadMarker {
+            marker_event_id: event_id,
+            events_since_marker: 0,
+        },
+    );
+}
+
+/// Returns the room's fully-read marker and how many events have arrived
+/// since, or `None` if the room has no marker yet (e.g. never opened).
+#[tauri::command]
+pub fn get_first_unread_event(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+) -> Option<UnreadInfo> {
+    state
+        .read_markers
+        .by_room
+        .lock()
+        .unwrap()
+        .get(&room_id)
+        .map(|marker| UnreadInfo {
+            marker_event_id: marker.marker_event_id.clone(),
+            events_since_marker: marker.events_since_marker,
+        })
+}
@@ -0,0 +1,63 @@
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistrationStage {
+    Captcha,
+    Terms,
+    Complete,
+}
+
+/// Tracks where a single in-progress registration attempt is in its
+/// multi-stage flow, so stages can't be submitted out of order.
+#[derive(Default)]
+pub struct RegistrationFlow {
+    current: Mutex<Option<RegistrationStage>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptchaChallenge {
+    pub site_key: String,
+}
+
+/// Starts (or restarts) a registration flow and returns the CAPTCHA
+/// challenge to render, the first stage in this flow.
+#[tauri::command]
+pub fn start_registration(
+    state: tauri::State<'_, crate::state::AppState>,
+    site_key: String,
+) -> CaptchaChallenge {
+    *state.registration.current.lock().unwrap() = Some(RegistrationStage::Captcha);
+    CaptchaChallenge { site_key }
+}
+
+/// Submits the response for `stage`, rejecting it if it isn't the stage
+/// the flow is currently waiting on.
+#[tauri::command]
+pub fn submit_registration_stage(
+    state: tauri::State<'_, crate::state::AppState>,
+    stage: RegistrationStage,
+    _response_token: String,
+) -> Result<RegistrationStage, AppError> {
+    let mut current = state.registration.current.lock().unwrap();
+    match *current {
+        Some(expected) if expected == stage => {
+            let next = match stage {
+                RegistrationStage::Captcha => RegistrationStage::Terms,
+                RegistrationStage::Terms => RegistrationStage::Complete,
+                RegistrationStage::Complete => RegistrationStage::Complete,
+            };
+            *current = Some(next);
+            Ok(next)
+        }
+        Some(expected) => Err(AppError::Other(format!(
+            "expected stage {expected:?}, got {stage:?}"
+        ))),
+        None => Err(AppError::Other("no registration flow is in progress".into())),
+    }
+}
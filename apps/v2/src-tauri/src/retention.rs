@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use tauri::{AppHandle, Manager};
+
+use crate::error::AppError;
+
+/// Per-room retention, in days, as recorded by [`set_room_retention`]. The
+/// recorded values are not read by any pruning: [`prune_expired_media`]
+/// takes its age limit as an argument, and events in the event cache are
+/// not pruned by retention.
+#[derive(Default)]
+pub struct RetentionPolicies {
+    max_age_days_by_room: Mutex<HashMap<String, u32>>,
+}
+
+#[tauri::command]
+pub fn set_room_retention(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    max_age_days: u32,
+) {
+    state
+        .retention
+        .max_age_days_by_room
+        .lock()
+        .unwrap()
+        .insert(room_id, max_age_days);
+}
+
+/// Deletes cached avatar/media files older than `max_age_days` so the local
+/// store doesn't keep data past that age. Only the avatar cache directory
+/// is pruned; cached events are left alone.
+#[tauri::command]
+pub fn prune_expired_media(app: AppHandle, max_age_days: u32) -> Result<u32, AppError> {
+    let cache_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| AppError::Other(e.to_string()))?
+        .join("avatars");
+
+    if !cache_dir.exists() {
+        return Ok(0);
+    }
+
+    let max_age = Duration::from_secs(max_age_days as u64 * 24 * 60 * 60);
+    let now = SystemTime::now();
+    let mut pruned = 0;
+
+    for entry in std::fs::read_dir(&cache_dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if let Ok(age) = now.duration_since(metadata.modified()?) {
+            if age > max_age {
+                std::fs::remove_file(entry.path())?;
+                pruned += 1;
+            }
+        }
+    }
+
+    Ok(pruned)
+}
@@ -0,0 +1,73 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use crate::error::AppError;
+
+/// Published aliases per room, plus a global reverse index used for
+/// availability checks and canonical-alias lookups.
+#[derive(Default)]
+pub struct RoomAliases {
+    by_room: Mutex<HashMap<String, Vec<String>>>,
+    canonical: Mutex<HashMap<String, String>>,
+    taken: Mutex<HashSet<String>>,
+}
+
+#[tauri::command]
+pub fn get_room_aliases(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+) -> Vec<String> {
+    state.room_aliases.by_room.lock().unwrap().get(&room_id).cloned().unwrap_or_default()
+}
+
+#[tauri::command]
+pub fn add_room_alias(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    alias: String,
+) -> Result<(), AppError> {
+    let mut taken = state.room_aliases.taken.lock().unwrap();
+    if !taken.insert(alias.clone()) {
+        return Err(AppError::Other(format!("alias {alias} is already in use")));
+    }
+    state
+        .room_aliases
+        .by_room
+        .lock()
+        .unwrap()
+        .entry(room_id)
+        .or_default()
+        .push(alias);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn remove_room_alias(state: tauri::State<'_, crate::state::AppState>, alias: String) {
+    state.room_aliases.taken.lock().unwrap().remove(&alias);
+    for aliases in state.room_aliases.by_room.lock().unwrap().values_mut() {
+        aliases.retain(|a| a != &alias);
+    }
+    state.room_aliases.canonical.lock().unwrap().retain(|_, v| v != &alias);
+}
+
+/// Sets `alias` as the canonical (primary, published) alias for `room_id`;
+/// it must already have been added via `add_room_alias`.
+#[tauri::command]
+pub fn set_canonical_alias(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    alias: String,
+) -> Result<(), AppError> {
+    let owns_alias = state
+        .room_aliases
+        .by_room
+        .lock()
+        .unwrap()
+        .get(&room_id)
+        .is_some_and(|aliases| aliases.contains(&alias));
+    if !owns_alias {
+        return Err(AppError::Other(format!("{alias} is not an alias of this room")));
+    }
+    state.room_aliases.canonical.lock().unwrap().insert(room_id, alias);
+    Ok(())
+}
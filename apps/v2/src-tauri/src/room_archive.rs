@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use rusqlite::params;
+use serde::Serialize;
+
+use crate::error::AppError;
+use crate::event_cache::CachedEvent;
+
+/// Rooms the local user has left, kept around read-only so their cached
+/// history doesn't just vanish the moment they leave — mirroring the
+/// Matrix `/forget` semantics, where leaving and forgetting are separate
+/// steps and a left room stays visible (as "Archived") until forgotten.
+#[derive(Default)]
+pub struct ArchivedRooms {
+    left_at_ms: Mutex<HashMap<String, i64>>,
+}
+
+impl ArchivedRooms {
+    pub(crate) fn mark_left(&self, room_id: String, left_at_ms: i64) {
+        self.left_at_ms.lock().unwrap().insert(room_id, left_at_ms);
+    }
+
+    pub(crate) fn is_archived(&self, room_id: &str) -> bool {
+        self.left_at_ms.lock().unwrap().contains_key(room_id)
+    }
+
+    pub(crate) fn summaries(&self) -> Vec<ArchivedRoomSummary> {
+        let mut rooms: Vec<ArchivedRoomSummary> = self
+            .left_at_ms
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(room_id, &left_at_ms)| ArchivedRoomSummary { room_id: room_id.clone(), left_at_ms })
+            .collect();
+        rooms.sort_by(|a, b| b.left_at_ms.cmp(&a.left_at_ms));
+        rooms
+    }
+
+    pub(crate) fn forget(&self, room_id: &str) {
+        self.left_at_ms.lock().unwrap().remove(room_id);
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedRoomSummary {
+    pub room_id: String,
+    pub left_at_ms: i64,
+}
+
+/// Moves a room into the Archived section. Called once the leave itself has
+/// been applied elsewhere (this is bookkeeping only — it doesn't talk to a
+/// homeserver).
+#[tauri::command]
+pub fn mark_room_left(state: tauri::State<'_, crate::state::AppState>, room_id: String, left_at_ms: i64) {
+    state.room_archive.mark_left(room_id, left_at_ms);
+}
+
+#[tauri::command]
+pub fn get_archived_rooms(state: tauri::State<'_, crate::state::AppState>) -> Vec<ArchivedRoomSummary> {
+    state.room_archive.summaries()
+}
+
+/// Opens an archived room's cached timeline read-only. Errors if the room
+/// isn't actually archived, so the caller can't use this to sneak a read of
+/// a still-joined room's cache through the wrong door.
+#[tauri::command]
+pub fn load_archived_timeline(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+) -> Result<Vec<CachedEvent>, AppError> {
+    load_timeline(&state.room_archive, &state.event_cache, &room_id)
+}
+
+fn load_timeline(
+    archived_rooms: &ArchivedRooms,
+    event_cache: &crate::event_cache::EventCache,
+    room_id: &str,
+) -> Result<Vec<CachedEvent>, AppError> {
+    if !archived_rooms.is_archived(room_id) {
+        return Err(AppError::Other("room is not archived".into()));
+    }
+    let conn = event_cache.connection().lock().unwrap();
+    let mut stmt = conn.prepare(
+        "SELECT event_id, room_id, received_order, content_json, pinned FROM cached_events \
+         WHERE room_id = ?1 ORDER BY received_order ASC",
+    )?;
+    let rows = stmt
+        .query_map(params![room_id], |row| {
+            Ok(CachedEvent {
+                event_id: row.get(0)?,
+                room_id: row.get(1)?,
+                received_order: row.get(2)?,
+                content_json: row.get(3)?,
+                pinned: row.get(4)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(rows)
+}
+
+/// Permanently purges a left room's local history and drops it from the
+/// Archived section, mirroring `/forget`'s irreversibility — there is no
+/// undo once the homeserver forgets a room either.
+#[tauri::command]
+pub fn forget_room(state: tauri::State<'_, crate::state::AppState>, room_id: String) -> Result<(), AppError> {
+    state.room_archive.forget(&room_id);
+    state
+        .event_cache
+        .connection()
+        .lock()
+        .unwrap()
+        .execute("DELETE FROM cached_events WHERE room_id = ?1", params![room_id])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_cache::EventCache;
+
+    fn insert_event(event_cache: &EventCache, event_id: &str, room_id: &str, received_order: i64) {
+        event_cache
+            .connection()
+            .lock()
+            .unwrap()
+            .execute(
+                "INSERT INTO cached_events (event_id, room_id, received_order, content_json, pinned) VALUES (?1, ?2, ?3, '{}', 0)",
+                params![event_id, room_id, received_order],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn archived_rooms_are_sorted_most_recently_left_first() {
+        let archived = ArchivedRooms::default();
+        archived.mark_left("!old:example.org".to_string(), 100);
+        archived.mark_left("!new:example.org".to_string(), 200);
+
+        let summaries = archived.summaries();
+        assert_eq!(summaries[0].room_id, "!new:example.org");
+        assert_eq!(summaries[1].room_id, "!old:example.org");
+    }
+
+    #[test]
+    fn load_timeline_rejects_a_room_that_is_not_archived() {
+        let archived = ArchivedRooms::default();
+        let event_cache = EventCache::default();
+        let result = load_timeline(&archived, &event_cache, "!not-archived:example.org");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn load_timeline_returns_an_archived_rooms_events_in_order() {
+        let archived = ArchivedRooms::default();
+        let event_cache = EventCache::default();
+        archived.mark_left("!room:example.org".to_string(), 100);
+        insert_event(&event_cache, "$b", "!room:example.org", 2);
+        insert_event(&event_cache, "$a", "!room:example.org", 1);
+
+        let events = load_timeline(&archived, &event_cache, "!room:example.org").unwrap();
+        assert_eq!(events.iter().map(|e| e.event_id.as_str()).collect::<Vec<_>>(), vec!["$a", "$b"]);
+    }
+
+    #[test]
+    fn forget_drops_a_room_from_the_archived_list() {
+        let archived = ArchivedRooms::default();
+        archived.mark_left("!room:example.org".to_string(), 100);
+        archived.forget("!room:example.org");
+        assert!(!archived.is_archived("!room:example.org"));
+    }
+}
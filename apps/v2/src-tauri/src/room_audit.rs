@@ -0,0 +1,29 @@
+//! Paginated room state-change history (who changed the name/topic/power
+//! levels and when), for a moderator audit view of community spaces.
+//!
+//! This client has no room-state ingestion yet (see [`crate::bridge_awareness`]
+//! for the same gap blocking bridge detection) — nothing stores past state
+//! events for a room to paginate back through. Recording the request here
+//! rather than dropping it; a real `get_room_state_history` needs that
+//! ingestion wired up first.
+use serde::Serialize;
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateChangeEntry {
+    pub event_type: String,
+    pub changed_by: String,
+    pub timestamp_ms: i64,
+    pub previous_content_json: String,
+    pub new_content_json: String,
+}
+
+#[tauri::command]
+pub fn get_room_state_history(
+    _room_id: String,
+    _event_types: Vec<String>,
+) -> Result<Vec<StateChangeEntry>, AppError> {
+    Err(AppError::Other("not applicable: this client has no room-state ingestion".into()))
+}
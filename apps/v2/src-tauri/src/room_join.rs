@@ -0,0 +1,61 @@
+//! Joining a room with fallback across multiple via servers (as supplied by
+//! a matrix.to link's `via` query params or a space child's `via` state),
+//! since a federated room's join frequently fails through one server (it's
+//! offline, or doesn't actually participate in that room) while another
+//! would have worked.
+//!
+//! There's no room-join network pipeline in this tree yet (no homeserver
+//! API client for it), so the retry loop and its progress events are real,
+//! but the join attempt itself always fails — once a real pipeline exists,
+//! [`attempt_join_via`] is the only function that needs to change.
+use serde::Serialize;
+use tauri::AppHandle;
+
+use crate::error::AppError;
+
+pub const ROOM_JOIN_PROGRESS: &str = "room-join://progress";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub enum RoomJoinProgress {
+    TryingServer { server: String, attempt: usize, of: usize },
+    ServerFailed { server: String, error: String },
+}
+
+/// Attempts to join `room_id_or_alias`, trying each of `via_servers` in
+/// order and only giving up once all of them have failed, emitting
+/// [`RoomJoinProgress`] after every attempt so the UI can show which
+/// server is currently being tried instead of one long silent spinner.
+#[tauri::command]
+pub async fn join_room(app: AppHandle, room_id_or_alias: String, via_servers: Vec<String>) -> Result<(), AppError> {
+    if via_servers.is_empty() {
+        return Err(AppError::Other("no via servers were given to attempt a join through".into()));
+    }
+
+    let total = via_servers.len();
+    for (index, server) in via_servers.iter().enumerate() {
+        crate::streams::coalesced_emit(
+            &app,
+            ROOM_JOIN_PROGRESS,
+            RoomJoinProgress::TryingServer { server: server.clone(), attempt: index + 1, of: total },
+        );
+        match attempt_join_via(&room_id_or_alias, server).await {
+            Ok(()) => return Ok(()),
+            Err(error) => {
+                crate::streams::coalesced_emit(
+                    &app,
+                    ROOM_JOIN_PROGRESS,
+                    RoomJoinProgress::ServerFailed { server: server.clone(), error },
+                );
+            }
+        }
+    }
+
+    Err(AppError::Other(format!(
+        "failed to join {room_id_or_alias} through any of {total} via server(s): no room join pipeline is implemented yet"
+    )))
+}
+
+async fn attempt_join_via(_room_id_or_alias: &str, _server: &str) -> Result<(), String> {
+    Err("no room join pipeline implemented yet".into())
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomSummaryInput {
+    pub room_id: String,
+    pub is_invite: bool,
+    pub is_favourite: bool,
+    pub is_low_priority: bool,
+    pub is_direct: bool,
+    pub last_activity_ms: i64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RoomSectionKind {
+    Invites,
+    Favourites,
+    People,
+    Rooms,
+    LowPriority,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomSection {
+    pub kind: RoomSectionKind,
+    pub room_ids: Vec<String>,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomListSections {
+    pub sections: Vec<RoomSection>,
+}
+
+/// Buckets rooms into the standard sections — most specific wins, so an
+/// invited favourite DM still shows under Invites rather than People — and
+/// orders each section's rooms most-recently-active first, so the frontend
+/// list view only has to render pre-sectioned data instead of re-deriving
+/// the ordering rules per platform.
+#[tauri::command]
+pub fn compute_room_list_sections(rooms: Vec<RoomSummaryInput>) -> RoomListSections {
+    let mut invites = Vec::new();
+    let mut favourites = Vec::new();
+    let mut people = Vec::new();
+    let mut regular = Vec::new();
+    let mut low_priority = Vec::new();
+
+    for room in rooms {
+        let bucket = if room.is_invite {
+            &mut invites
+        } else if room.is_low_priority {
+            &mut low_priority
+        } else if room.is_favourite {
+            &mut favourites
+        } else if room.is_direct {
+            &mut people
+        } else {
+            &mut regular
+        };
+        bucket.push(room);
+    }
+
+    let sections = [
+        (RoomSectionKind::Invites, invites),
+        (RoomSectionKind::Favourites, favourites),
+        (RoomSectionKind::People, people),
+        (RoomSectionKind::Rooms, regular),
+        (RoomSectionKind::LowPriority, low_priority),
+    ]
+    .into_iter()
+    .map(|(kind, mut rooms)| {
+        rooms.sort_by(|a, b| b.last_activity_ms.cmp(&a.last_activity_ms));
+        let room_ids: Vec<String> = rooms.into_iter().map(|r| r.room_id).collect();
+        RoomSection { count: room_ids.len(), kind, room_ids }
+    })
+    .collect();
+
+    RoomListSections { sections }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn room(id: &str, last_activity_ms: i64) -> RoomSummaryInput {
+        RoomSummaryInput {
+            room_id: id.to_string(),
+            is_invite: false,
+            is_favourite: false,
+            is_low_priority: false,
+            is_direct: false,
+            last_activity_ms,
+        }
+    }
+
+    fn section(sections: &RoomListSections, kind: RoomSectionKind) -> &RoomSection {
+        sections.sections.iter().find(|s| s.kind == kind).unwrap()
+    }
+
+    #[test]
+    fn invite_takes_precedence_over_every_other_flag() {
+        let mut invited_favourite = room("!a:example.org", 0);
+        invited_favourite.is_invite = true;
+        invited_favourite.is_favourite = true;
+
+        let result = compute_room_list_sections(vec![invited_favourite]);
+        assert_eq!(section(&result, RoomSectionKind::Invites).room_ids, vec!["!a:example.org"]);
+        assert!(section(&result, RoomSectionKind::Favourites).room_ids.is_empty());
+    }
+
+    #[test]
+    fn low_priority_beats_favourite_and_direct() {
+        let mut room = room("!a:example.org", 0);
+        room.is_low_priority = true;
+        room.is_favourite = true;
+        room.is_direct = true;
+
+        let result = compute_room_list_sections(vec![room]);
+        assert_eq!(section(&result, RoomSectionKind::LowPriority).room_ids, vec!["!a:example.org"]);
+    }
+
+    #[test]
+    fn each_section_sorts_most_recently_active_first() {
+        let rooms = vec![room("!old:example.org", 100), room("!new:example.org", 200)];
+        let result = compute_room_list_sections(rooms);
+        assert_eq!(
+            section(&result, RoomSectionKind::Rooms).room_ids,
+            vec!["!new:example.org", "!old:example.org"]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+pub(crate) const AVATAR_PALETTE: [&str; 8] = [
+    "#e03131", "#f08c00", "#2f9e44", "#1971c2", "#7048e8", "#c2255c", "#0c8599", "#e8590c",
+];
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomNameInput {
+    pub explicit_name: Option<String>,
+    pub canonical_alias: Option<String>,
+    /// Other members' display names, used for the DM/heroes fallback when
+    /// the room has no explicit name or alias.
+    pub other_member_names: Vec<String>,
+    pub is_empty: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomDisplayInfo {
+    pub display_name: String,
+    pub avatar_initials: String,
+    pub avatar_color: &'static str,
+}
+
+/// Computes a room's display name and a deterministic avatar fallback
+/// (initials + palette color), following the same precedence on every
+/// platform: explicit name, then canonical alias, then a heroes-style list
+/// of other members, then an empty-room placeholder.
+#[tauri::command]
+pub fn compute_room_display_info(input: RoomNameInput) -> RoomDisplayInfo {
+    let display_name = if let Some(name) = input.explicit_name.filter(|n| !n.is_empty()) {
+        name
+    } else if let Some(alias) = input.canonical_alias.filter(|a| !a.is_empty()) {
+        alias
+    } else if input.is_empty || input.other_member_names.is_empty() {
+        "Empty room".to_string()
+    } else {
+        name_from_heroes(&input.other_member_names)
+    };
+
+    let avatar_color = AVATAR_PALETTE[palette_index(&display_name)];
+    let avatar_initials = initials(&display_name);
+
+    RoomDisplayInfo {
+        display_name,
+        avatar_initials,
+        avatar_color,
+    }
+}
+
+fn name_from_heroes(names: &[String]) -> String {
+    match names.len() {
+        1 => names[0].clone(),
+        2 => format!("{} and {}", names[0], names[1]),
+        n if n > 2 => format!("{} and {} others", names[0], n - 1),
+        _ => "Empty room".to_string(),
+    }
+}
+
+pub(crate) fn palette_index(seed: &str) -> usize {
+    let hash = seed.bytes().fold(0u32, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u32));
+    (hash as usize) % AVATAR_PALETTE.len()
+}
+
+fn initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .collect::<String>()
+        .to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(explicit_name: Option<&str>, canonical_alias: Option<&str>, other_member_names: &[&str], is_empty: bool) -> RoomNameInput {
+        RoomNameInput {
+            explicit_name: explicit_name.map(String::from),
+            canonical_alias: canonical_alias.map(String::from),
+            other_member_names: other_member_names.iter().map(|s| s.to_string()).collect(),
+            is_empty,
+        }
+    }
+
+    #[test]
+    fn explicit_name_wins_over_everything_else() {
+        let info = compute_room_display_info(input(Some("Team Room"), Some("#team:example.org"), &["Alice"], false));
+        assert_eq!(info.display_name, "Team Room");
+    }
+
+    #[test]
+    fn canonical_alias_is_used_when_there_is_no_explicit_name() {
+        let info = compute_room_display_info(input(None, Some("#team:example.org"), &["Alice"], false));
+        assert_eq!(info.display_name, "#team:example.org");
+    }
+
+    #[test]
+    fn falls_back_to_heroes_list_with_two_members() {
+        let info = compute_room_display_info(input(None, None, &["Alice", "Bob"], false));
+        assert_eq!(info.display_name, "Alice and Bob");
+    }
+
+    #[test]
+    fn falls_back_to_heroes_list_with_more_than_two_members() {
+        let info = compute_room_display_info(input(None, None, &["Alice", "Bob", "Carol"], false));
+        assert_eq!(info.display_name, "Alice and 2 others");
+    }
+
+    #[test]
+    fn empty_room_falls_back_to_placeholder() {
+        let info = compute_room_display_info(input(None, None, &[], true));
+        assert_eq!(info.display_name, "Empty room");
+    }
+
+    #[test]
+    fn avatar_color_and_initials_are_deterministic() {
+        let a = compute_room_display_info(input(Some("Team Room"), None, &[], false));
+        let b = compute_room_display_info(input(Some("Team Room"), None, &[], false));
+        assert_eq!(a.avatar_color, b.avatar_color);
+        assert_eq!(a.avatar_initials, "TR");
+    }
+}
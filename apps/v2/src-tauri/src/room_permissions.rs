@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// The subset of `m.room.power_levels` this client needs to decide what the
+/// local user can do. Mirrors the spec's own defaults so a room that's
+/// never set an override still computes sane permissions.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerLevelsInput {
+    pub my_power_level: i64,
+    #[serde(default = "default_events_default")]
+    pub events_default: i64,
+    #[serde(default = "default_state_default")]
+    pub state_default: i64,
+    #[serde(default = "default_redact")]
+    pub redact: i64,
+    #[serde(default = "default_invite")]
+    pub invite: i64,
+    /// Power level required to send `m.room.message` with `msgtype` other
+    /// than `m.text`/`m.notice`/`m.emote` — distinct from `events_default`
+    /// because some rooms restrict media without restricting text chat.
+    #[serde(default = "default_events_default")]
+    pub send_media_level: i64,
+    #[serde(default = "default_events_default")]
+    pub reaction_level: i64,
+}
+
+fn default_events_default() -> i64 { 0 }
+fn default_state_default() -> i64 { 50 }
+fn default_redact() -> i64 { 50 }
+fn default_invite() -> i64 { 0 }
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomPermissions {
+    pub can_send_message: bool,
+    pub can_send_media: bool,
+    pub can_react: bool,
+    pub can_redact_others: bool,
+    pub can_invite: bool,
+    pub can_change_settings: bool,
+}
+
+/// Computes what the composer and its surrounding controls should allow,
+/// so the UI can disable a button proactively instead of letting the user
+/// fill out a send and only then finding out the homeserver will reject
+/// it with an M_FORBIDDEN.
+#[tauri::command]
+pub fn get_room_permissions(power_levels: PowerLevelsInput) -> RoomPermissions {
+    RoomPermissions {
+        can_send_message: power_levels.my_power_level >= power_levels.events_default,
+        can_send_media: power_levels.my_power_level >= power_levels.send_media_level,
+        can_react: power_levels.my_power_level >= power_levels.reaction_level,
+        can_redact_others: power_levels.my_power_level >= power_levels.redact,
+        can_invite: power_levels.my_power_level >= power_levels.invite,
+        can_change_settings: power_levels.my_power_level >= power_levels.state_default,
+    }
+}
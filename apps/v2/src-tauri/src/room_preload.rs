@@ -0,0 +1,134 @@
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tokio::sync::Semaphore;
+
+use crate::error::AppError;
+
+/// Which rooms the UI wants preloaded after login, most-recent-first. The
+/// list is also the priority hint consumers can re-read to show "this room
+/// will load next" in the UI while preloading is still in flight.
+#[derive(Default)]
+pub struct RoomPreloadState {
+    priority: Mutex<Vec<String>>,
+}
+
+impl RoomPreloadState {
+    pub(crate) fn set_priority(&self, room_ids: Vec<String>) {
+        *self.priority.lock().unwrap() = room_ids;
+    }
+
+    pub(crate) fn priority(&self) -> Vec<String> {
+        self.priority.lock().unwrap().clone()
+    }
+
+    pub(crate) fn bump(&self, room_id: String) {
+        let mut priority = self.priority.lock().unwrap();
+        priority.retain(|id| id != &room_id);
+        priority.insert(0, room_id);
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomPreloadResult {
+    pub room_id: String,
+    pub loaded: bool,
+    pub error: Option<String>,
+}
+
+/// Records the rooms to preload, most-recent-first, for `preload_initial_rooms`
+/// to work through.
+#[tauri::command]
+pub fn set_preload_priority(state: tauri::State<'_, crate::state::AppState>, room_ids: Vec<String>) {
+    state.room_preload.set_priority(room_ids);
+}
+
+/// Returns the current preload order, so the UI can hint which room will
+/// load next.
+#[tauri::command]
+pub fn get_preload_priority(state: tauri::State<'_, crate::state::AppState>) -> Vec<String> {
+    state.room_preload.priority()
+}
+
+/// Moves `room_id` to the front of the priority list, for when the UI
+/// knows the user is about to open it (e.g. hovering it in the room list)
+/// and wants it preloaded ahead of its recency rank.
+#[tauri::command]
+pub fn bump_preload_priority(state: tauri::State<'_, crate::state::AppState>, room_id: String) {
+    state.room_preload.bump(room_id);
+}
+
+/// Preloads the last timeline page of the top `count` rooms in priority
+/// order, with at most `concurrency` fetches in flight at once, so opening
+/// a chat right after login doesn't have to wait on a full initial sync.
+///
+/// This client has no sync engine to fetch a timeline page from yet, so
+/// every preload reports `loaded: false` with an explanatory error; the
+/// priority-list bookkeeping and bounded concurrency above are real and
+/// ready for `preload_one_room` to do a real fetch once a sync engine
+/// exists.
+#[tauri::command]
+pub async fn preload_initial_rooms(
+    state: tauri::State<'_, crate::state::AppState>,
+    count: usize,
+    concurrency: usize,
+) -> Result<Vec<RoomPreloadResult>, AppError> {
+    let room_ids: Vec<String> = state.room_preload.priority().into_iter().take(count).collect();
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+
+    let handles: Vec<_> = room_ids
+        .into_iter()
+        .map(|room_id| {
+            let semaphore = semaphore.clone();
+            tauri::async_runtime::spawn(async move {
+                let _permit = semaphore.acquire_owned().await.ok();
+                preload_one_room(room_id).await
+            })
+        })
+        .collect();
+
+    let mut results = Vec::with_capacity(handles.len());
+    for handle in handles {
+        if let Ok(result) = handle.await {
+            results.push(result);
+        }
+    }
+    Ok(results)
+}
+
+async fn preload_one_room(room_id: String) -> RoomPreloadResult {
+    RoomPreloadResult {
+        room_id,
+        loaded: false,
+        error: Some("no sync engine to fetch a timeline page from yet".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priority_round_trips_through_set_and_get() {
+        let state = RoomPreloadState::default();
+        state.set_priority(vec!["!a:example.org".to_string(), "!b:example.org".to_string()]);
+        assert_eq!(state.priority(), vec!["!a:example.org".to_string(), "!b:example.org".to_string()]);
+    }
+
+    #[test]
+    fn bump_moves_an_existing_room_to_the_front() {
+        let state = RoomPreloadState::default();
+        state.set_priority(vec!["!a:example.org".to_string(), "!b:example.org".to_string()]);
+        state.bump("!b:example.org".to_string());
+        assert_eq!(state.priority(), vec!["!b:example.org".to_string(), "!a:example.org".to_string()]);
+    }
+
+    #[test]
+    fn bump_inserts_an_unseen_room_at_the_front() {
+        let state = RoomPreloadState::default();
+        state.set_priority(vec!["!a:example.org".to_string()]);
+        state.bump("!new:example.org".to_string());
+        assert_eq!(state.priority(), vec!["!new:example.org".to_string(), "!a:example.org".to_string()]);
+    }
+}
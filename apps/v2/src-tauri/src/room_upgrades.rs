@@ -0,0 +1,51 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use crate::error::AppError;
+
+/// Tracks `m.room.tombstone` successors learned from room state, and which
+/// of those successors the user has already joined, so a send can be
+/// transparently re-targeted at an already-joined successor instead of
+/// failing with a stale room id.
+#[derive(Default)]
+pub struct RoomUpgrades {
+    successors: Mutex<HashMap<String, String>>,
+    joined: Mutex<HashSet<String>>,
+}
+
+/// Records that `old_room_id` was tombstoned in favor of `new_room_id`,
+/// called when the client observes an `m.room.tombstone` state event.
+#[tauri::command]
+pub fn record_room_upgrade(
+    state: tauri::State<'_, crate::state::AppState>,
+    old_room_id: String,
+    new_room_id: String,
+) {
+    state.room_upgrades.successors.lock().unwrap().insert(old_room_id, new_room_id);
+}
+
+/// Records that the user has joined `room_id`, so it becomes a valid
+/// re-target destination for sends to a room it superseded.
+#[tauri::command]
+pub fn mark_room_joined(state: tauri::State<'_, crate::state::AppState>, room_id: String) {
+    state.room_upgrades.joined.lock().unwrap().insert(room_id);
+}
+
+/// Resolves the room id a send to `room_id` should actually target:
+/// `room_id` itself if it has no known successor, the newest joined
+/// successor in its upgrade chain, or a typed [`AppError::RoomUpgraded`]
+/// naming the successor if it hasn't been joined yet, so the caller can
+/// join it (or surface a prompt) before the send is retried.
+pub fn resolve_send_target(state: &crate::state::AppState, room_id: &str) -> Result<String, AppError> {
+    let successors = state.room_upgrades.successors.lock().unwrap();
+    let joined = state.room_upgrades.joined.lock().unwrap();
+
+    let mut current = room_id.to_string();
+    while let Some(successor) = successors.get(&current) {
+        if !joined.contains(successor) {
+            return Err(AppError::RoomUpgraded { new_room_id: successor.clone() });
+        }
+        current = successor.clone();
+    }
+    Ok(current)
+}
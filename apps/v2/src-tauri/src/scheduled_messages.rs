@@ -0,0 +1,87 @@
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::error::AppError;
+
+/// A message queued to send at a future time, for the "scheduled" composer
+/// menu. Persisted in-memory only for now — the same store `resume_pending_sends`
+/// will eventually back this onto, once `send_queue`'s schema grows a
+/// `scheduled_for` column instead of duplicating a second sqlite table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledMessage {
+    pub id: String,
+    pub room_id: String,
+    pub body: String,
+    pub send_at_rfc3339: String,
+}
+
+/// Event emitted when a scheduled message's time has come, for the sync
+/// service (or whatever eventually owns sending) to actually deliver it.
+pub const SCHEDULED_MESSAGE_DUE: &str = "scheduled-messages://due";
+
+#[derive(Default)]
+pub struct ScheduledMessages {
+    items: Mutex<Vec<ScheduledMessage>>,
+}
+
+#[tauri::command]
+pub fn schedule_message(
+    state: tauri::State<'_, crate::state::AppState>,
+    id: String,
+    room_id: String,
+    body: String,
+    send_at_rfc3339: String,
+) -> Result<(), AppError> {
+    DateTime::parse_from_rfc3339(&send_at_rfc3339).map_err(|e| AppError::Other(e.to_string()))?;
+    state
+        .scheduled_messages
+        .items
+        .lock()
+        .unwrap()
+        .push(ScheduledMessage { id, room_id, body, send_at_rfc3339 });
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_scheduled_messages(state: tauri::State<'_, crate::state::AppState>) -> Vec<ScheduledMessage> {
+    state.scheduled_messages.items.lock().unwrap().clone()
+}
+
+#[tauri::command]
+pub fn cancel_scheduled_message(state: tauri::State<'_, crate::state::AppState>, id: String) {
+    state.scheduled_messages.items.lock().unwrap().retain(|m| m.id != id);
+}
+
+/// Background loop that, once a minute, emits [`SCHEDULED_MESSAGE_DUE`] for
+/// every scheduled message whose time has come (including any whose time
+/// passed between checks, since it only checks "is this due now", not
+/// "did we hit the exact minute"), and removes them from the queue. The
+/// queue is held in memory, so messages scheduled before the app was
+/// closed are not delivered after a restart. Called once from `run()`'s
+/// setup hook alongside the other background loops.
+pub fn spawn_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let due: Vec<ScheduledMessage> = {
+                let state = app.state::<crate::state::AppState>();
+                let mut items = state.scheduled_messages.items.lock().unwrap();
+                let now = crate::clock_skew::corrected_now(&state);
+                let (due, pending): (Vec<_>, Vec<_>) = items.drain(..).partition(|m| {
+                    DateTime::parse_from_rfc3339(&m.send_at_rfc3339)
+                        .map(|t| t.with_timezone(&Utc) <= now)
+                        .unwrap_or(true)
+                });
+                *items = pending;
+                due
+            };
+            for message in due {
+                let _ = app.emit(SCHEDULED_MESSAGE_DUE, &message);
+            }
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+        }
+    });
+}
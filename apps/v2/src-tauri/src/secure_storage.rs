@@ -0,0 +1,480 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{AppHandle, Emitter};
+
+use crate::error::AppError;
+
+/// Event emitted when a key is set or deleted through the secure-storage
+/// commands below (`set_*`, `remove_*`, `clear_*`) — e.g. so the auth layer
+/// can react immediately when the session is cleared instead of
+/// discovering it on the next read. Writes made directly through
+/// [`SecureStorage`] (such as `get_or_generate`, or `get` dropping an
+/// expired entry) do not emit it. The frontend filters by the `prefix`
+/// [`watch_storage_keys`] returns, since Tauri's event system matches on
+/// exact names rather than content.
+pub const STORAGE_KEY_CHANGED: &str = "secure-storage://key-changed";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StorageKeyChange {
+    key: String,
+    deleted: bool,
+}
+
+/// Holds the [`SecureStorage`] instance once `init_app` knows where the
+/// app's data directory lives; commands borrow it from here instead of
+/// each resolving the path themselves.
+#[derive(Default)]
+pub struct SecureStorageState {
+    inner: Mutex<Option<SecureStorage>>,
+}
+
+impl SecureStorageState {
+    pub fn install(&self, storage: SecureStorage) {
+        *self.inner.lock().unwrap() = Some(storage);
+    }
+
+    pub(crate) fn with<R>(&self, f: impl FnOnce(&SecureStorage) -> Result<R, AppError>) -> Result<R, AppError> {
+        let guard = self.inner.lock().unwrap();
+        let storage = guard
+            .as_ref()
+            .ok_or_else(|| AppError::Other("secure storage has not been initialized".into()))?;
+        f(storage)
+    }
+}
+
+#[tauri::command]
+pub fn get_secure_storage_secret(
+    state: tauri::State<'_, crate::state::AppState>,
+    key: String,
+) -> Result<Option<String>, AppError> {
+    state.secure_storage.with(|storage| storage.get(&key))
+}
+
+#[tauri::command]
+pub fn set_secure_storage_secret(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    key: String,
+    value: String,
+) -> Result<(), AppError> {
+    state.secure_storage.with(|storage| storage.set(&key, &value))?;
+    crate::streams::coalesced_emit(&app, STORAGE_KEY_CHANGED, StorageKeyChange { key, deleted: false });
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_secure_storage_secret_with_ttl(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    key: String,
+    value: String,
+    ttl_ms: u64,
+) -> Result<(), AppError> {
+    state
+        .secure_storage
+        .with(|storage| storage.set_with_ttl(&key, &value, std::time::Duration::from_millis(ttl_ms)))?;
+    crate::streams::coalesced_emit(&app, STORAGE_KEY_CHANGED, StorageKeyChange { key, deleted: false });
+    Ok(())
+}
+
+#[tauri::command]
+pub fn remove_secure_storage_secret(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    key: String,
+) -> Result<(), AppError> {
+    state.secure_storage.with(|storage| storage.remove(&key))?;
+    crate::streams::coalesced_emit(&app, STORAGE_KEY_CHANGED, StorageKeyChange { key, deleted: true });
+    Ok(())
+}
+
+#[tauri::command]
+pub fn clear_secure_storage(app: AppHandle, state: tauri::State<'_, crate::state::AppState>) -> Result<(), AppError> {
+    let cleared_keys = state.secure_storage.with(|storage| {
+        let keys = storage.keys()?;
+        storage.clear()?;
+        Ok(keys)
+    })?;
+    // One event per key, emitted directly: coalescing would deliver only
+    // the first and last key of the burst.
+    for key in cleared_keys {
+        let _ = app.emit(STORAGE_KEY_CHANGED, StorageKeyChange { key, deleted: true });
+    }
+    Ok(())
+}
+
+/// Returns the Tauri event name to subscribe to for storage key changes,
+/// and the key prefix the frontend should filter by, since this channel is
+/// shared across every key in storage.
+#[tauri::command]
+pub fn watch_storage_keys(prefix: String) -> (String, String) {
+    (STORAGE_KEY_CHANGED.to_string(), prefix)
+}
+
+/// Structured report from [`secure_storage_self_test`], for support to
+/// debug "I keep getting logged out" reports without needing to reproduce
+/// them locally.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTestReport {
+    pub backend_name: &'static str,
+    pub round_trip_succeeded: bool,
+    pub round_trip_latency_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Performs a set/get/delete round trip against the installed backend with
+/// a throwaway key, measuring latency and reporting any permission problem
+/// encountered along the way instead of letting it surface as an opaque
+/// later failure.
+#[tauri::command]
+pub fn secure_storage_self_test(state: tauri::State<'_, crate::state::AppState>) -> Result<SelfTestReport, AppError> {
+    state.secure_storage.with(|storage| {
+        const SELF_TEST_KEY: &str = "__self_test__";
+        let started_at = std::time::Instant::now();
+        let outcome = (|| -> Result<(), AppError> {
+            storage.set(SELF_TEST_KEY, "ok")?;
+            let readback = storage.get(SELF_TEST_KEY)?;
+            if readback.as_deref() != Some("ok") {
+                return Err(AppError::Other("round trip returned an unexpected value".into()));
+            }
+            storage.remove(SELF_TEST_KEY)
+        })();
+        let round_trip_latency_ms = started_at.elapsed().as_millis() as u64;
+        Ok(SelfTestReport {
+            backend_name: storage.backend_name(),
+            round_trip_succeeded: outcome.is_ok(),
+            round_trip_latency_ms,
+            error: outcome.err().map(|e| e.to_string()),
+        })
+    })
+}
+
+/// Returns the name of the secure-storage backend in use (e.g. for a Linux
+/// build, which of Secret Service/KWallet/an encrypted-file fallback). This
+/// tree has no such fallback chain yet — only the generic file backend — so
+/// this always returns `"file"` until platform-specific backends land.
+#[tauri::command]
+pub fn get_backend_name(state: tauri::State<'_, crate::state::AppState>) -> Result<&'static str, AppError> {
+    state.secure_storage.with(|storage| Ok(storage.backend_name()))
+}
+
+#[tauri::command]
+pub fn get_or_generate_db_encryption_key(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<String, AppError> {
+    state.secure_storage.with(|storage| storage.get_or_generate("db_encryption_key", 32))
+}
+
+/// Key-value storage for secrets (database encryption keys, session
+/// tokens) that must not sit in a plain file alongside the rest of app
+/// data. This first cut keeps secrets in a single file under the app's
+/// private data directory with OS file permissions as the only protection;
+/// platform keychain backends (macOS Keychain, Windows DPAPI, Linux
+/// Secret Service) replace this file-based fallback one at a time as they
+/// land, behind the same `get`/`set` contract.
+///
+/// This backend has neither of the two problems a Windows Credential
+/// Manager backend would: every `get`/`set` path below returns
+/// `Result<_, AppError>` rather than panicking (no `expect()` across an FFI
+/// boundary to remove), and a JSON file on disk has no 2560-byte
+/// per-credential limit to silently truncate large values like crypto
+/// pickles against, so no chunking is needed here. A future
+/// `WindowsStorage` backend implementing the same contract would need both.
+pub struct SecureStorage {
+    path: PathBuf,
+}
+
+impl SecureStorage {
+    pub fn new(app_data_dir: &std::path::Path) -> Self {
+        SecureStorage { path: app_data_dir.join(".secure_storage") }
+    }
+
+    fn load(&self) -> Result<serde_json::Map<String, serde_json::Value>, AppError> {
+        if !self.path.exists() {
+            return Ok(serde_json::Map::new());
+        }
+        let contents = std::fs::read_to_string(&self.path)?;
+        serde_json::from_str(&contents).map_err(|e| AppError::Other(e.to_string()))
+    }
+
+    fn save(&self, map: &serde_json::Map<String, serde_json::Value>) -> Result<(), AppError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string(map).map_err(|e| AppError::Other(e.to_string()))?;
+        std::fs::write(&self.path, contents)?;
+        set_owner_only_permissions(&self.path)?;
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Result<Option<String>, AppError> {
+        let mut map = self.load()?;
+        let Some(entry) = map.get(key).cloned() else {
+            return Ok(None);
+        };
+        match decode_entry(&entry) {
+            Some((value, None)) => Ok(Some(value)),
+            Some((value, Some(expires_at))) if expires_at > Utc::now() => Ok(Some(value)),
+            Some(_) => {
+                // Expired: garbage-collect lazily and treat as not found.
+                map.remove(key);
+                self.save(&map)?;
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn set(&self, key: &str, value: &str) -> Result<(), AppError> {
+        let mut map = self.load()?;
+        map.insert(key.to_string(), Value::String(value.to_string()));
+        self.save(&map)
+    }
+
+    /// Stores `value` under `key` alongside an expiry `ttl` from now. Once
+    /// expired, [`get`](Self::get) treats the entry as absent and removes it
+    /// the next time it's looked up — useful for cached OpenID tokens, TURN
+    /// credentials and SSO login tokens that must not outlive their
+    /// server-issued lifetime.
+    pub fn set_with_ttl(&self, key: &str, value: &str, ttl: std::time::Duration) -> Result<(), AppError> {
+        let expires_at = Utc::now() + chrono::Duration::from_std(ttl).map_err(|e| AppError::Other(e.to_string()))?;
+        let mut map = self.load()?;
+        map.insert(
+            key.to_string(),
+            serde_json::json!({ "value": value, "expiresAt": expires_at.to_rfc3339() }),
+        );
+        self.save(&map)
+    }
+
+    pub fn remove(&self, key: &str) -> Result<(), AppError> {
+        let mut map = self.load()?;
+        map.remove(key);
+        self.save(&map)
+    }
+
+    /// Name of the backend actually in use, for the settings screen to tell
+    /// users where their secrets live. This tree has no Secret
+    /// Service/KWallet/encrypted-file fallback chain to choose between yet
+    /// (see [`get_backend_name`]) — there is only ever the one backend
+    /// below, so this always returns `"file"`.
+    pub fn backend_name(&self) -> &'static str {
+        "file"
+    }
+
+    /// Deletes every stored entry. This tree has only the single
+    /// file-backed backend above (no per-platform Keychain/Credential
+    /// Manager/Secret Service module yet), so clearing it is just emptying
+    /// the one file rather than enumerating a platform key index.
+    pub fn clear(&self) -> Result<(), AppError> {
+        self.save(&serde_json::Map::new())
+    }
+
+    /// Every currently-stored key, so a caller that needs to react per-key
+    /// (e.g. [`clear_secure_storage`] emitting one [`STORAGE_KEY_CHANGED`]
+    /// per entry) doesn't have to re-implement reading the backing file.
+    pub fn keys(&self) -> Result<Vec<String>, AppError> {
+        Ok(self.load()?.keys().cloned().collect())
+    }
+
+    /// Returns the stored value for `key`, generating and persisting a new
+    /// random hex secret of `byte_length` bytes if one doesn't exist yet.
+    pub fn get_or_generate(&self, key: &str, byte_length: usize) -> Result<String, AppError> {
+        if let Some(existing) = self.get(key)? {
+            return Ok(existing);
+        }
+        let mut bytes = vec![0u8; byte_length];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        let value = bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+        self.set(key, &value)?;
+        Ok(value)
+    }
+}
+
+/// Reads a stored entry as either a plain string (no TTL) or a
+/// `{value, expiresAt}` object, returning the value and its expiry if any.
+fn decode_entry(entry: &Value) -> Option<(String, Option<DateTime<Utc>>)> {
+    if let Some(value) = entry.as_str() {
+        return Some((value.to_string(), None));
+    }
+    let value = entry.get("value")?.as_str()?.to_string();
+    let expires_at = entry
+        .get("expiresAt")
+        .and_then(|v| v.as_str())
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&Utc));
+    Some((value, expires_at))
+}
+
+/// A configurable test double for [`SecureStorage`] that lets downstream
+/// tests exercise failure paths a real backend can't be reliably coaxed
+/// into: failing on a specific call number, simulating latency, or
+/// simulating a permission failure on every call. Not used by the app
+/// itself — only built when the `test-utils` feature is enabled.
+#[cfg(feature = "test-utils")]
+pub struct FaultyStorage {
+    inner: Mutex<std::collections::HashMap<String, String>>,
+    call_count: Mutex<u32>,
+    fail_on_call: Option<u32>,
+    simulate_access_denied: bool,
+    artificial_latency: std::time::Duration,
+}
+
+#[cfg(feature = "test-utils")]
+impl FaultyStorage {
+    pub fn new() -> Self {
+        FaultyStorage {
+            inner: Mutex::new(std::collections::HashMap::new()),
+            call_count: Mutex::new(0),
+            fail_on_call: None,
+            simulate_access_denied: false,
+            artificial_latency: std::time::Duration::ZERO,
+        }
+    }
+
+    /// The Nth call (1-indexed) to `get`/`set`/`remove` will fail.
+    pub fn fail_on_call(mut self, n: u32) -> Self {
+        self.fail_on_call = Some(n);
+        self
+    }
+
+    /// Every call fails with a permission error, as if the OS keychain
+    /// denied access.
+    pub fn simulate_access_denied(mut self) -> Self {
+        self.simulate_access_denied = true;
+        self
+    }
+
+    pub fn with_latency(mut self, latency: std::time::Duration) -> Self {
+        self.artificial_latency = latency;
+        self
+    }
+
+    fn tick(&self) -> Result<(), AppError> {
+        if !self.artificial_latency.is_zero() {
+            std::thread::sleep(self.artificial_latency);
+        }
+        if self.simulate_access_denied {
+            return Err(AppError::Other("access denied".into()));
+        }
+        let mut count = self.call_count.lock().unwrap();
+        *count += 1;
+        if self.fail_on_call == Some(*count) {
+            return Err(AppError::Other(format!("simulated failure on call {count}")));
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Result<Option<String>, AppError> {
+        self.tick()?;
+        Ok(self.inner.lock().unwrap().get(key).cloned())
+    }
+
+    pub fn set(&self, key: &str, value: &str) -> Result<(), AppError> {
+        self.tick()?;
+        self.inner.lock().unwrap().insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    pub fn remove(&self, key: &str) -> Result<(), AppError> {
+        self.tick()?;
+        self.inner.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl Default for FaultyStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "test-utils"))]
+mod faulty_storage_tests {
+    use super::*;
+
+    #[test]
+    fn fails_only_on_the_configured_call_number() {
+        let storage = FaultyStorage::new().fail_on_call(2);
+        assert!(storage.set("a", "1").is_ok());
+        assert!(storage.set("a", "2").is_err());
+        assert!(storage.set("a", "3").is_ok());
+    }
+
+    #[test]
+    fn simulate_access_denied_fails_every_call() {
+        let storage = FaultyStorage::new().simulate_access_denied();
+        assert!(storage.get("a").is_err());
+        assert!(storage.set("a", "1").is_err());
+    }
+
+    #[test]
+    fn behaves_like_a_normal_store_with_no_faults_configured() {
+        let storage = FaultyStorage::new();
+        storage.set("a", "1").unwrap();
+        assert_eq!(storage.get("a").unwrap(), Some("1".to_string()));
+        storage.remove("a").unwrap();
+        assert_eq!(storage.get("a").unwrap(), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage_in_temp_dir(label: &str) -> SecureStorage {
+        let dir = std::env::temp_dir().join(format!("skiffy_secure_storage_test_{label}_{:?}", std::thread::current().id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        SecureStorage::new(&dir)
+    }
+
+    #[test]
+    fn set_then_get_round_trips() {
+        let storage = storage_in_temp_dir("round_trip");
+        storage.set("k", "v").unwrap();
+        assert_eq!(storage.get("k").unwrap(), Some("v".to_string()));
+    }
+
+    #[test]
+    fn clear_empties_keys_and_keys_reports_it() {
+        let storage = storage_in_temp_dir("clear");
+        storage.set("a", "1").unwrap();
+        storage.set("b", "2").unwrap();
+        let mut keys_before = storage.keys().unwrap();
+        keys_before.sort();
+        assert_eq!(keys_before, vec!["a".to_string(), "b".to_string()]);
+
+        storage.clear().unwrap();
+        assert!(storage.keys().unwrap().is_empty());
+        assert_eq!(storage.get("a").unwrap(), None);
+    }
+
+    #[test]
+    fn remove_deletes_a_single_key() {
+        let storage = storage_in_temp_dir("remove");
+        storage.set("a", "1").unwrap();
+        storage.set("b", "2").unwrap();
+        storage.remove("a").unwrap();
+        assert_eq!(storage.keys().unwrap(), vec!["b".to_string()]);
+    }
+}
+
+
+#[cfg(unix)]
+fn set_owner_only_permissions(path: &std::path::Path) -> Result<(), AppError> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_owner_only_permissions(_path: &std::path::Path) -> Result<(), AppError> {
+    Ok(())
+}
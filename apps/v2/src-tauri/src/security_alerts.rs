@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+/// Event emitted when a device not previously seen for the account appears
+/// in the session list, or a known device's user agent or last-seen IP
+/// changes, so the app can prompt "New login on Windows — was this you?".
+pub const SECURITY_ALERT: &str = "security-alerts://new-device";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceSession {
+    pub device_id: String,
+    pub user_agent: String,
+    pub last_seen_ip: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityAlert {
+    pub device_id: String,
+    pub user_agent: String,
+    pub last_seen_ip: String,
+}
+
+#[derive(Default)]
+pub struct SeenDevices {
+    known: Mutex<HashMap<String, DeviceSession>>,
+}
+
+/// Compares `current_devices` (the account's current session list, fetched
+/// by whatever already calls the device-list endpoint) against the
+/// previously seen set, emitting [`SECURITY_ALERT`] for every device id
+/// that's new or whose user agent / last-seen IP changed. Updates the seen
+/// set with `current_devices` afterwards so repeated calls don't re-alert.
+#[tauri::command]
+pub fn check_device_list(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    current_devices: Vec<DeviceSession>,
+) {
+    let mut known = state.security_alerts.known.lock().unwrap();
+    for device in &current_devices {
+        let changed = known
+            .get(&device.device_id)
+            .map(|previous| previous.user_agent != device.user_agent || previous.last_seen_ip != device.last_seen_ip)
+            .unwrap_or(true);
+        if changed {
+            // Emitted directly, not coalesced: each alert names a different
+            // device, so a later one must not replace an earlier one.
+            let _ = app.emit(
+                SECURITY_ALERT,
+                SecurityAlert {
+                    device_id: device.device_id.clone(),
+                    user_agent: device.user_agent.clone(),
+                    last_seen_ip: device.last_seen_ip.clone(),
+                },
+            );
+        }
+    }
+    known.clear();
+    known.extend(current_devices.into_iter().map(|d| (d.device_id.clone(), d)));
+}
+
+/// Returns the Tauri event name to subscribe to for new-device alerts.
+#[tauri::command]
+pub fn watch_security_alerts() -> &'static str {
+    SECURITY_ALERT
+}
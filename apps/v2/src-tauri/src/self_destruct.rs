@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Per-room opt-in for self-destructing messages, plus the messages being
+/// tracked for expiry. Nothing in this module adds a `skiffy.self_destruct`
+/// timestamp to outgoing messages or sends a redaction: the reaper only
+/// emits [`SELF_DESTRUCT_EXPIRED`], with `should_redact` set for the
+/// sender's own messages.
+#[derive(Default)]
+pub struct SelfDestructState {
+    enabled_rooms: Mutex<std::collections::HashSet<String>>,
+    tracked: Mutex<HashMap<String, TrackedMessage>>,
+}
+
+struct TrackedMessage {
+    room_id: String,
+    expires_at: DateTime<Utc>,
+    is_own: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfDestructingMessage {
+    pub event_id: String,
+    pub room_id: String,
+    pub expires_at_rfc3339: String,
+    pub is_own: bool,
+}
+
+#[tauri::command]
+pub fn set_self_destruct_enabled(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+    enabled: bool,
+) {
+    let mut rooms = state.self_destruct.enabled_rooms.lock().unwrap();
+    if enabled {
+        rooms.insert(room_id);
+    } else {
+        rooms.remove(&room_id);
+    }
+}
+
+#[tauri::command]
+pub fn is_self_destruct_enabled(state: tauri::State<'_, crate::state::AppState>, room_id: String) -> bool {
+    state.self_destruct.enabled_rooms.lock().unwrap().contains(&room_id)
+}
+
+/// Starts tracking `event_id` for expiry. Called once per self-destructing
+/// message, whether it's one of the user's own outgoing messages (to be
+/// redacted for everyone once expired) or an incoming one (to be hidden
+/// locally only).
+#[tauri::command]
+pub fn track_self_destructing_message(
+    state: tauri::State<'_, crate::state::AppState>,
+    message: SelfDestructingMessage,
+) -> Result<(), crate::error::AppError> {
+    let expires_at = DateTime::parse_from_rfc3339(&message.expires_at_rfc3339)
+        .map_err(|e| crate::error::AppError::Other(e.to_string()))?
+        .with_timezone(&Utc);
+    state.self_destruct.tracked.lock().unwrap().insert(
+        message.event_id,
+        TrackedMessage { room_id: message.room_id, expires_at, is_own: message.is_own },
+    );
+    Ok(())
+}
+
+/// Event emitted for every message the reaper decides has expired, so the
+/// frontend can hide it immediately and, for the sender's own messages, so
+/// whatever owns redaction can issue the real redaction request.
+pub const SELF_DESTRUCT_EXPIRED: &str = "self-destruct://expired";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExpiredMessage {
+    event_id: String,
+    room_id: String,
+    should_redact: bool,
+}
+
+/// Background reaper: once a minute, finds every tracked message past its
+/// expiry, emits [`SELF_DESTRUCT_EXPIRED`] for it, and stops tracking it.
+/// Called once from `run()`'s setup hook alongside the other background
+/// loops.
+pub fn spawn_reaper(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let expired: Vec<(String, ExpiredMessage)> = {
+                let state = app.state::<crate::state::AppState>();
+                let mut tracked = state.self_destruct.tracked.lock().unwrap();
+                let now = crate::clock_skew::corrected_now(&state);
+                let expired_ids: Vec<String> = tracked
+                    .iter()
+                    .filter(|(_, m)| m.expires_at <= now)
+                    .map(|(id, _)| id.clone())
+                    .collect();
+                expired_ids
+                    .into_iter()
+                    .filter_map(|id| {
+                        tracked.remove(&id).map(|m| {
+                            (
+                                id.clone(),
+                                ExpiredMessage { event_id: id, room_id: m.room_id, should_redact: m.is_own },
+                            )
+                        })
+                    })
+                    .collect()
+            };
+            // Emitted directly, not coalesced, so no expiry in a batch is
+            // replaced by a later one.
+            for (_, message) in expired {
+                let _ = app.emit(SELF_DESTRUCT_EXPIRED, message);
+            }
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+        }
+    });
+}
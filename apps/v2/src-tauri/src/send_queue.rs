@@ -0,0 +1,174 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Outgoing event or attachment upload that must survive the OS killing the
+/// app mid-send: persisted to sqlite on enqueue, removed on confirmed
+/// delivery, and replayed by `resume_pending_sends` on the next startup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingSend {
+    pub local_id: String,
+    pub room_id: String,
+    pub event_type: String,
+    pub content_json: String,
+    /// Bytes already uploaded for an attached media file, if any, so a
+    /// resumable upload can continue from where it left off instead of
+    /// restarting from zero.
+    pub uploaded_offset: u64,
+    pub attachment_total_bytes: Option<u64>,
+}
+
+pub struct SendQueue {
+    conn: Mutex<Connection>,
+}
+
+impl Default for SendQueue {
+    fn default() -> Self {
+        let conn = Connection::open_in_memory().expect("failed to open in-memory send queue db");
+        init_schema(&conn).expect("failed to initialize send queue schema");
+        SendQueue { conn: Mutex::new(conn) }
+    }
+}
+
+fn init_schema(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS pending_sends (
+            local_id TEXT PRIMARY KEY,
+            room_id TEXT NOT NULL,
+            event_type TEXT NOT NULL,
+            content_json TEXT NOT NULL,
+            uploaded_offset INTEGER NOT NULL,
+            attachment_total_bytes INTEGER
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+/// Opens (or creates) the on-disk send queue database at `db_path`,
+/// encrypted with `encryption_key` (a SQLCipher passphrase — see
+/// [`crate::secure_storage::SecureStorage::get_or_generate`]), so pending
+/// sends persist across process restarts instead of living only in the
+/// in-memory default used before a session's storage location is known,
+/// and aren't left sitting on disk unencrypted in the meantime.
+#[tauri::command]
+pub fn open_send_queue(
+    state: tauri::State<'_, crate::state::AppState>,
+    db_path: PathBuf,
+    encryption_key: String,
+) -> Result<(), AppError> {
+    let conn = Connection::open(db_path)?;
+    apply_encryption_key(&conn, &encryption_key)?;
+    init_schema(&conn)?;
+    *state.send_queue.conn.lock().unwrap() = conn;
+    Ok(())
+}
+
+fn apply_encryption_key(conn: &Connection, key: &str) -> rusqlite::Result<()> {
+    conn.pragma_update(None, "key", key)
+}
+
+/// Re-encrypts the send queue's on-disk database with `new_key`, for
+/// rotating the SQLCipher passphrase (e.g. after a suspected key leak)
+/// without losing the pending sends already queued.
+#[tauri::command]
+pub fn rekey_local_stores(
+    state: tauri::State<'_, crate::state::AppState>,
+    new_key: String,
+) -> Result<(), AppError> {
+    state.send_queue.conn.lock().unwrap().pragma_update(None, "rekey", new_key)?;
+    Ok(())
+}
+
+/// Enqueues `send`, first re-targeting its room id through any known
+/// `m.room.tombstone` chain (see [`crate::room_upgrades::resolve_send_target`])
+/// so a message aimed at a room that was since upgraded lands in its
+/// already-joined successor, or fails fast with a typed `RoomUpgraded`
+/// error naming the successor to join instead of being queued against a
+/// dead room.
+#[tauri::command]
+pub fn enqueue_pending_send(
+    state: tauri::State<'_, crate::state::AppState>,
+    mut send: PendingSend,
+) -> Result<(), AppError> {
+    send.room_id = crate::room_upgrades::resolve_send_target(&state, &send.room_id)?;
+
+    state.send_queue.conn.lock().unwrap().execute(
+        "INSERT OR REPLACE INTO pending_sends
+            (local_id, room_id, event_type, content_json, uploaded_offset, attachment_total_bytes)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            send.local_id,
+            send.room_id,
+            send.event_type,
+            send.content_json,
+            send.uploaded_offset as i64,
+            send.attachment_total_bytes.map(|v| v as i64),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Called once a send has been confirmed by the server, so it is no longer
+/// replayed on the next `resume_pending_sends`.
+#[tauri::command]
+pub fn complete_pending_send(
+    state: tauri::State<'_, crate::state::AppState>,
+    local_id: String,
+) -> Result<(), AppError> {
+    state
+        .send_queue
+        .conn
+        .lock()
+        .unwrap()
+        .execute("DELETE FROM pending_sends WHERE local_id = ?1", params![local_id])?;
+    Ok(())
+}
+
+/// Updates the resumable-upload progress for a pending send's attachment,
+/// called periodically while a chunked upload is in flight so a process
+/// death loses at most the last unflushed chunk.
+#[tauri::command]
+pub fn update_pending_send_offset(
+    state: tauri::State<'_, crate::state::AppState>,
+    local_id: String,
+    uploaded_offset: u64,
+) -> Result<(), AppError> {
+    state.send_queue.conn.lock().unwrap().execute(
+        "UPDATE pending_sends SET uploaded_offset = ?1 WHERE local_id = ?2",
+        params![uploaded_offset as i64, local_id],
+    )?;
+    Ok(())
+}
+
+/// Reads back every send left over from a previous process, ordered oldest
+/// first, so callers can reattempt them in the order they were originally
+/// queued and replay local echoes into the timeline stream. Actually
+/// reattempting the network send is the caller's responsibility — this
+/// command only restores the durable queue contents.
+#[tauri::command]
+pub fn resume_pending_sends(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<Vec<PendingSend>, AppError> {
+    let conn = state.send_queue.conn.lock().unwrap();
+    let mut stmt = conn.prepare(
+        "SELECT local_id, room_id, event_type, content_json, uploaded_offset, attachment_total_bytes
+         FROM pending_sends ORDER BY rowid ASC",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok(PendingSend {
+            local_id: row.get(0)?,
+            room_id: row.get(1)?,
+            event_type: row.get(2)?,
+            content_json: row.get(3)?,
+            uploaded_offset: row.get::<_, i64>(4)? as u64,
+            attachment_total_bytes: row.get::<_, Option<i64>>(5)?.map(|v| v as u64),
+        })
+    })?;
+    rows.collect::<rusqlite::Result<Vec<_>>>().map_err(AppError::from)
+}
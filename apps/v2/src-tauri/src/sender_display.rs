@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::room_name::{palette_index, AVATAR_PALETTE};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomMemberName {
+    pub user_id: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SenderDisplay {
+    pub user_id: String,
+    pub display_name: String,
+    pub color: &'static str,
+}
+
+/// Computes each member's sender color (stable per user id, so it never
+/// changes when they rename themselves) and disambiguated display name
+/// (the MXID appended in parentheses when two or more members share a
+/// display name), in one pass over the room's member list so every
+/// platform renders senders identically and the FFI payload stays a flat
+/// list instead of per-message lookups.
+#[tauri::command]
+pub fn compute_sender_displays(members: Vec<RoomMemberName>) -> Vec<SenderDisplay> {
+    let mut name_counts: HashMap<&str, u32> = HashMap::new();
+    for member in &members {
+        *name_counts.entry(member.display_name.as_str()).or_insert(0) += 1;
+    }
+
+    members
+        .into_iter()
+        .map(|member| {
+            let display_name = if name_counts.get(member.display_name.as_str()).copied().unwrap_or(0) > 1 {
+                format!("{} ({})", member.display_name, member.user_id)
+            } else {
+                member.display_name
+            };
+            let color = AVATAR_PALETTE[palette_index(&member.user_id)];
+            SenderDisplay { user_id: member.user_id, display_name, color }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(user_id: &str, display_name: &str) -> RoomMemberName {
+        RoomMemberName { user_id: user_id.to_string(), display_name: display_name.to_string() }
+    }
+
+    #[test]
+    fn unique_display_names_are_left_as_is() {
+        let result = compute_sender_displays(vec![member("@alice:example.org", "Alice")]);
+        assert_eq!(result[0].display_name, "Alice");
+    }
+
+    #[test]
+    fn colliding_display_names_are_disambiguated_with_the_mxid() {
+        let result = compute_sender_displays(vec![
+            member("@alice:example.org", "Sam"),
+            member("@bob:example.org", "Sam"),
+        ]);
+        assert_eq!(result[0].display_name, "Sam (@alice:example.org)");
+        assert_eq!(result[1].display_name, "Sam (@bob:example.org)");
+    }
+
+    #[test]
+    fn color_is_stable_for_the_same_user_id_regardless_of_name() {
+        let a = compute_sender_displays(vec![member("@alice:example.org", "Alice")]);
+        let b = compute_sender_displays(vec![member("@alice:example.org", "Renamed")]);
+        assert_eq!(a[0].color, b[0].color);
+    }
+}
@@ -0,0 +1,114 @@
+//! Viewing and editing a room's `m.room.server_acl`, with validation that
+//! moderators don't accidentally lock their own server out of the room.
+//!
+//! This client has no state-event read/write pipeline yet (see
+//! [`crate::spaces`] for the same gap), so [`get_server_acl`] and
+//! [`set_server_acl`] have nothing to fetch from or send to. The
+//! lockout validation is pure logic and doesn't depend on that pipeline,
+//! so it's implemented for real and run before the write is attempted.
+use crate::error::AppError;
+
+/// Returns `Ok(())` if `own_server` would still be allowed to participate
+/// in the room under the given allow/deny lists, or an error naming why
+/// it wouldn't. Matches the homeserver's own server_acl matching rules:
+/// `deny` entries are checked first, then `allow` must contain a match
+/// (glob `*`/`?` wildcards, exact otherwise), so an empty `allow` list
+/// allows no server at all.
+pub fn check_not_locked_out(own_server: &str, allow: &[String], deny: &[String]) -> Result<(), AppError> {
+    if deny.iter().any(|pattern| glob_matches(pattern, own_server)) {
+        return Err(AppError::Other(format!(
+            "this server_acl would deny {own_server}'s own server — refusing to apply it"
+        )));
+    }
+    if !allow.iter().any(|pattern| glob_matches(pattern, own_server)) {
+        return Err(AppError::Other(format!(
+            "this server_acl's allow list does not include {own_server}'s own server — refusing to apply it"
+        )));
+    }
+    Ok(())
+}
+
+/// Server names and DNS hostnames are case-insensitive, and so is the
+/// homeserver's own server_acl matching — `pattern`/`value` are lowercased
+/// before comparison so `check_not_locked_out` agrees with what the
+/// homeserver will actually enforce.
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    let pattern_chars: Vec<char> = pattern.to_lowercase().chars().collect();
+    let value_chars: Vec<char> = value.to_lowercase().chars().collect();
+    glob_match_from(&pattern_chars, &value_chars)
+}
+
+fn glob_match_from(pattern: &[char], value: &[char]) -> bool {
+    match pattern.first() {
+        None => value.is_empty(),
+        Some('*') => {
+            (0..=value.len()).any(|split| glob_match_from(&pattern[1..], &value[split..]))
+        }
+        Some('?') => !value.is_empty() && glob_match_from(&pattern[1..], &value[1..]),
+        Some(c) => value.first() == Some(c) && glob_match_from(&pattern[1..], &value[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_are_case_insensitive() {
+        assert!(glob_matches("evil.example.org", "Evil.example.org"));
+        assert!(glob_matches("*.example.org", "EVIL.EXAMPLE.ORG"));
+    }
+
+    #[test]
+    fn exact_pattern_matches_only_the_same_value() {
+        assert!(glob_matches("example.org", "example.org"));
+        assert!(!glob_matches("example.org", "notexample.org"));
+    }
+
+    #[test]
+    fn star_matches_any_run_of_characters() {
+        assert!(glob_matches("*.example.org", "a.example.org"));
+        assert!(glob_matches("*.example.org", "a.b.example.org"));
+        assert!(!glob_matches("*.example.org", "example.org"));
+    }
+
+    #[test]
+    fn question_mark_matches_exactly_one_character() {
+        assert!(glob_matches("ex?mple.org", "example.org"));
+        assert!(!glob_matches("ex?mple.org", "exxxmple.org"));
+    }
+
+    #[test]
+    fn own_server_matching_a_deny_pattern_is_locked_out() {
+        let result = check_not_locked_out("Evil.example.org", &["*".to_string()], &["evil.example.org".to_string()]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn own_server_must_match_a_non_empty_allow_list() {
+        let result = check_not_locked_out("own.example.org", &["allowed.example.org".to_string()], &[]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn empty_allow_list_locks_out_every_server() {
+        let result = check_not_locked_out("own.example.org", &[], &[]);
+        assert!(result.is_err());
+    }
+}
+
+#[tauri::command]
+pub fn get_server_acl(_room_id: String) -> Result<(), AppError> {
+    Err(AppError::Other("not applicable: this client has no state-event read pipeline".into()))
+}
+
+#[tauri::command]
+pub fn set_server_acl(
+    own_server: String,
+    allow: Vec<String>,
+    deny: Vec<String>,
+    _room_id: String,
+) -> Result<(), AppError> {
+    check_not_locked_out(&own_server, &allow, &deny)?;
+    Err(AppError::Other("not applicable: this client has no state-event write pipeline".into()))
+}
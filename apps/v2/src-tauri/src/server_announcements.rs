@@ -0,0 +1,93 @@
+//! Surfacing homeserver-originated notices so maintenance and deprecation
+//! don't come as a surprise mid-session.
+//!
+//! The `server_notices` room (a special room some deployments auto-invite
+//! accounts to with `m.room.message` events describing maintenance windows
+//! or policy changes) can't be read by this client yet — like
+//! [`crate::policy_lists`], that needs room-state ingestion this tree
+//! doesn't have, so [`check_server_notices_room`] records the gap honestly.
+//! `/_matrix/client/versions` deprecation, on the other hand, needs nothing
+//! but the same HTTP call [`crate::server_probe`] already makes, so
+//! [`check_server_deprecations`] does that part for real.
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::AppHandle;
+
+use crate::error::AppError;
+
+/// Event emitted for every new announcement, so the UI can surface it as a
+/// banner or toast instead of the user having to go looking for it.
+pub const SERVER_ANNOUNCEMENT: &str = "server-announcements://new";
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnouncementKind {
+    ServerNotice,
+    DeprecatedVersion,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerAnnouncement {
+    pub kind: AnnouncementKind,
+    pub message: String,
+}
+
+/// Dedupes announcements by message so a homeserver that's polled
+/// repeatedly (e.g. once per `connection.rs` health check interval) doesn't
+/// re-surface the same deprecation notice every time.
+#[derive(Default)]
+pub struct ServerAnnouncements {
+    seen: Mutex<HashSet<String>>,
+}
+
+/// The oldest spec version this client still fully supports. A homeserver
+/// that doesn't advertise at least this means it's old enough that the
+/// deployment's admin should be nudged to upgrade, or this client will need
+/// to start dropping support for it.
+const MIN_SUPPORTED_SPEC_VERSION: &str = "v1.1";
+
+#[tauri::command]
+pub async fn check_server_deprecations(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    homeserver_url: String,
+) -> Result<(), AppError> {
+    let versions_url = format!("{}/_matrix/client/versions", homeserver_url.trim_end_matches('/'));
+    let response = reqwest::get(&versions_url).await.map_err(|e| AppError::Other(e.to_string()))?;
+    let body: serde_json::Value = response.json().await.map_err(|e| AppError::Other(e.to_string()))?;
+    let versions: Vec<String> = body
+        .get("versions")
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+        .unwrap_or_default();
+
+    if !versions.iter().any(|v| v.as_str() >= MIN_SUPPORTED_SPEC_VERSION) {
+        let message = format!(
+            "this homeserver only advertises spec versions up to an old release; ask its admin to upgrade (client expects at least {MIN_SUPPORTED_SPEC_VERSION})"
+        );
+        emit_if_new(&app, &state, AnnouncementKind::DeprecatedVersion, message);
+    }
+
+    Ok(())
+}
+
+fn emit_if_new(app: &AppHandle, state: &crate::state::AppState, kind: AnnouncementKind, message: String) {
+    let mut seen = state.server_announcements.seen.lock().unwrap();
+    if seen.insert(message.clone()) {
+        drop(seen);
+        crate::streams::coalesced_emit(app, SERVER_ANNOUNCEMENT, ServerAnnouncement { kind, message });
+    }
+}
+
+#[tauri::command]
+pub fn check_server_notices_room(_room_id: String) -> Result<(), AppError> {
+    Err(AppError::Other("not applicable: this client has no room-state ingestion to read server_notices from".into()))
+}
+
+#[tauri::command]
+pub fn watch_server_announcements() -> &'static str {
+    SERVER_ANNOUNCEMENT
+}
@@ -0,0 +1,116 @@
+use std::sync::Mutex;
+
+use serde::Deserialize;
+use url::Url;
+
+use crate::error::AppError;
+
+/// Enterprise lockdown configuration: restricts login to an allow-list of
+/// homeserver domains and forces specific settings on, regardless of what
+/// the user or homeserver would otherwise allow.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerPolicy {
+    pub allowed_homeserver_domains: Vec<String>,
+    pub require_encryption: bool,
+    pub hide_public_room_directory: bool,
+}
+
+#[derive(Default)]
+pub struct ServerPolicyState {
+    policy: Mutex<Option<ServerPolicy>>,
+}
+
+impl ServerPolicyState {
+    pub(crate) fn install(&self, policy: ServerPolicy) {
+        *self.policy.lock().unwrap() = Some(policy);
+    }
+}
+
+/// Installs the enterprise policy for this deployment. Absent a policy
+/// (the default), every homeserver is allowed.
+#[tauri::command]
+pub fn set_server_policy(state: tauri::State<'_, crate::state::AppState>, policy: ServerPolicy) {
+    state.server_policy.install(policy);
+}
+
+/// Checks `homeserver_url` against the installed [`ServerPolicy`], so
+/// `login` can reject disallowed servers before ever making a network call.
+#[tauri::command]
+pub fn verify_homeserver(
+    state: tauri::State<'_, crate::state::AppState>,
+    homeserver_url: String,
+) -> Result<(), AppError> {
+    check_homeserver(&state.server_policy, &homeserver_url)
+}
+
+pub(crate) fn check_homeserver(state: &ServerPolicyState, homeserver_url: &str) -> Result<(), AppError> {
+    let Some(policy) = state.policy.lock().unwrap().clone() else {
+        return Ok(());
+    };
+    if policy.allowed_homeserver_domains.is_empty() {
+        return Ok(());
+    }
+
+    let host = Url::parse(homeserver_url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .ok_or_else(|| AppError::Other("not a valid homeserver url".into()))?;
+
+    if policy.allowed_homeserver_domains.iter().any(|domain| domain == &host) {
+        Ok(())
+    } else {
+        Err(AppError::ServerNotAllowed { homeserver: host })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_policy_allows_any_homeserver() {
+        let state = ServerPolicyState::default();
+        assert!(check_homeserver(&state, "https://matrix.example.org").is_ok());
+    }
+
+    #[test]
+    fn empty_allow_list_allows_any_homeserver() {
+        let state = ServerPolicyState::default();
+        state.install(ServerPolicy::default());
+        assert!(check_homeserver(&state, "https://matrix.example.org").is_ok());
+    }
+
+    #[test]
+    fn rejects_homeserver_outside_the_allow_list() {
+        let state = ServerPolicyState::default();
+        state.install(ServerPolicy {
+            allowed_homeserver_domains: vec!["allowed.example.org".to_string()],
+            ..Default::default()
+        });
+        assert!(matches!(
+            check_homeserver(&state, "https://evil.example.org"),
+            Err(AppError::ServerNotAllowed { .. })
+        ));
+    }
+
+    #[test]
+    fn allows_homeserver_inside_the_allow_list() {
+        let state = ServerPolicyState::default();
+        state.install(ServerPolicy {
+            allowed_homeserver_domains: vec!["allowed.example.org".to_string()],
+            ..Default::default()
+        });
+        assert!(check_homeserver(&state, "https://allowed.example.org").is_ok());
+    }
+
+    #[test]
+    fn rejects_an_unparseable_homeserver_url() {
+        let state = ServerPolicyState::default();
+        state.install(ServerPolicy {
+            allowed_homeserver_domains: vec!["allowed.example.org".to_string()],
+            ..Default::default()
+        });
+        assert!(check_homeserver(&state, "not a url").is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use std::time::Instant;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HomeserverProbeResult {
+    pub url: String,
+    pub reachable: bool,
+    pub latency_ms: Option<u64>,
+    pub server_version: Option<String>,
+}
+
+/// Probes every url in `urls` concurrently by fetching `/_matrix/client/versions`
+/// (present on every homeserver implementation regardless of which ones a
+/// deployment actually runs) and returns the results sorted fastest first,
+/// so onboarding can suggest the best of several candidate servers.
+#[tauri::command]
+pub async fn probe_homeservers(urls: Vec<String>) -> Vec<HomeserverProbeResult> {
+    let client = reqwest::Client::new();
+    let probes = urls.into_iter().map(|url| probe_one(client.clone(), url));
+    let mut results = futures_lite(probes).await;
+
+    results.sort_by(|a, b| match (a.latency_ms, b.latency_ms) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+    results
+}
+
+async fn probe_one(client: reqwest::Client, url: String) -> HomeserverProbeResult {
+    let versions_url = format!("{}/_matrix/client/versions", url.trim_end_matches('/'));
+    let started = Instant::now();
+
+    match client.get(&versions_url).send().await {
+        Ok(response) if response.status().is_success() => {
+            let latency_ms = started.elapsed().as_millis() as u64;
+            let server_version = response
+                .json::<serde_json::Value>()
+                .await
+                .ok()
+                .and_then(|json| json.get("versions").and_then(|v| v.get(0)).cloned())
+                .and_then(|v| v.as_str().map(str::to_string));
+            HomeserverProbeResult { url, reachable: true, latency_ms: Some(latency_ms), server_version }
+        }
+        _ => HomeserverProbeResult { url, reachable: false, latency_ms: None, server_version: None },
+    }
+}
+
+/// Runs every probe future concurrently and waits for all of them, without
+/// pulling in a join-all dependency for one call site.
+async fn futures_lite<F, T>(futures: impl Iterator<Item = F>) -> Vec<T>
+where
+    F: std::future::Future<Output = T>,
+{
+    let handles: Vec<_> = futures.map(tauri::async_runtime::spawn).collect();
+    let mut results = Vec::with_capacity(handles.len());
+    for handle in handles {
+        if let Ok(result) = handle.await {
+            results.push(result);
+        }
+    }
+    results
+}
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::secure_storage::SecureStorageState;
+use crate::server_policy::ServerPolicyState;
+
+/// Minimal shape this importer needs out of an Element Desktop
+/// `local_storage.json` export or a FluffyChat secure-storage session dump:
+/// both ultimately boil down to a homeserver URL, a user id, a device id
+/// and an access token.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalSessionExport {
+    pub user_id: String,
+    pub device_id: String,
+    pub homeserver_url: String,
+    pub access_token: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedSession {
+    pub user_id: String,
+    pub device_id: String,
+    pub homeserver_url: String,
+}
+
+/// Parses an Element Desktop or FluffyChat session export and stores its
+/// homeserver URL, device id and access token in secure storage under
+/// `imported_session.*`, bootstrapping a skiffy session without the user
+/// re-entering credentials or doing a fresh login dance on the new device.
+///
+/// Subject to the same enterprise homeserver allow-list as
+/// [`crate::auth::login`]/[`crate::auth::login_as_guest`] — otherwise a user
+/// could bypass a locked-down deployment's [`crate::server_policy::ServerPolicy`]
+/// simply by importing a session pointed at a disallowed server.
+///
+/// This only covers the plain access-token entry. A real export's E2EE
+/// keys backup (Megolm sessions encrypted under the user's recovery
+/// passphrase) can't be imported here — see
+/// [`import_external_keys_backup`] — because this client has no E2EE
+/// subsystem yet to hand decrypted sessions to.
+#[tauri::command]
+pub fn import_external_session(
+    state: tauri::State<'_, crate::state::AppState>,
+    export_json: String,
+) -> Result<ImportedSession, AppError> {
+    import_session(&state.server_policy, &state.secure_storage, &export_json)
+}
+
+fn import_session(
+    server_policy: &ServerPolicyState,
+    secure_storage: &SecureStorageState,
+    export_json: &str,
+) -> Result<ImportedSession, AppError> {
+    let export: ExternalSessionExport = serde_json::from_str(export_json)
+        .map_err(|e| AppError::Other(format!("unrecognized session export: {e}")))?;
+
+    crate::server_policy::check_homeserver(server_policy, &export.homeserver_url)?;
+
+    secure_storage.with(|storage| {
+        storage.set("imported_session.access_token", &export.access_token)?;
+        storage.set("imported_session.homeserver_url", &export.homeserver_url)?;
+        storage.set("imported_session.device_id", &export.device_id)
+    })?;
+
+    Ok(ImportedSession {
+        user_id: export.user_id,
+        device_id: export.device_id,
+        homeserver_url: export.homeserver_url,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server_policy::ServerPolicy;
+
+    fn storage_in_temp_dir(label: &str) -> SecureStorageState {
+        let dir = std::env::temp_dir()
+            .join(format!("skiffy_session_import_test_{label}_{:?}", std::thread::current().id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let state = SecureStorageState::default();
+        state.install(crate::secure_storage::SecureStorage::new(&dir));
+        state
+    }
+
+    fn export_json(homeserver_url: &str) -> String {
+        format!(
+            r#"{{"userId":"@alice:example.org","deviceId":"ABCDEF","homeserverUrl":"{homeserver_url}","accessToken":"secret-token"}}"#
+        )
+    }
+
+    #[test]
+    fn imports_and_persists_all_three_fields_when_allowed() {
+        let server_policy = ServerPolicyState::default();
+        let secure_storage = storage_in_temp_dir("allowed");
+
+        let session = import_session(&server_policy, &secure_storage, &export_json("https://example.org")).unwrap();
+        assert_eq!(session.homeserver_url, "https://example.org");
+
+        secure_storage
+            .with(|storage| {
+                assert_eq!(storage.get("imported_session.access_token").unwrap(), Some("secret-token".to_string()));
+                assert_eq!(storage.get("imported_session.homeserver_url").unwrap(), Some("https://example.org".to_string()));
+                assert_eq!(storage.get("imported_session.device_id").unwrap(), Some("ABCDEF".to_string()));
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn rejects_import_from_a_disallowed_homeserver() {
+        let server_policy = ServerPolicyState::default();
+        server_policy.install(ServerPolicy {
+            allowed_homeserver_domains: vec!["allowed.example.org".to_string()],
+            ..Default::default()
+        });
+        let secure_storage = storage_in_temp_dir("disallowed");
+
+        let result = import_session(&server_policy, &secure_storage, &export_json("https://evil.example.org"));
+        assert!(result.is_err());
+        secure_storage
+            .with(|storage| {
+                assert_eq!(storage.get("imported_session.access_token").unwrap(), None);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn rejects_unrecognized_json() {
+        let server_policy = ServerPolicyState::default();
+        let secure_storage = storage_in_temp_dir("bad_json");
+        assert!(import_session(&server_policy, &secure_storage, "not json").is_err());
+    }
+}
+
+/// Imports the E2EE keys backup bundled in a session export, so message
+/// history stays decryptable on the new device. Not possible yet: this
+/// client has no E2EE subsystem to hold decrypted Megolm sessions in.
+#[tauri::command]
+pub fn import_external_keys_backup(_export_json: String, _passphrase: String) -> Result<(), AppError> {
+    Err(AppError::Other("no E2EE subsystem to import a keys backup into".into()))
+}
@@ -0,0 +1,98 @@
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::error::AppError;
+
+pub const SETTINGS_CHANGED: &str = "settings://changed";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    Light,
+    Dark,
+    System,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::System
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaAutoDownloadPolicy {
+    Always,
+    WifiOnly,
+    Never,
+}
+
+impl Default for MediaAutoDownloadPolicy {
+    fn default() -> Self {
+        MediaAutoDownloadPolicy::WifiOnly
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Settings {
+    pub theme: Theme,
+    pub notifications_enabled_by_default: bool,
+    pub media_auto_download: MediaAutoDownloadPolicy,
+}
+
+/// Locally cached copy of the `skiffy.settings` account data event, plus a
+/// version counter bumped on every local write. The version lets a later
+/// sync pull tell whether the local copy or the server's is newer
+/// (last-write-wins by version), the same strategy `resolve_conflict`
+/// below applies once an account-data sync pipeline exists to feed it a
+/// remote copy.
+#[derive(Default)]
+pub struct SettingsService {
+    current: Mutex<Settings>,
+    version: Mutex<u64>,
+}
+
+#[tauri::command]
+pub fn get_settings(state: tauri::State<'_, crate::state::AppState>) -> Settings {
+    state.settings.current.lock().unwrap().clone()
+}
+
+/// Updates the locally cached settings and notifies `watch_settings_changes`
+/// subscribers. Bumps the local version so a later account-data sync knows
+/// this copy is newer than whatever it last pushed.
+#[tauri::command]
+pub fn set_settings(app: AppHandle, state: tauri::State<'_, crate::state::AppState>, settings: Settings) {
+    *state.settings.current.lock().unwrap() = settings.clone();
+    *state.settings.version.lock().unwrap() += 1;
+    crate::streams::coalesced_emit(&app, SETTINGS_CHANGED, settings);
+}
+
+#[tauri::command]
+pub fn watch_settings_changes() -> &'static str {
+    SETTINGS_CHANGED
+}
+
+/// Resolves a conflict between the local cache and a `skiffy.settings`
+/// account-data event pulled from another device: the higher version
+/// wins; a tie keeps the local copy rather than flipping settings back
+/// and forth on simultaneous writes.
+pub fn resolve_conflict(local: (Settings, u64), remote: (Settings, u64)) -> Settings {
+    if remote.1 > local.1 {
+        remote.0
+    } else {
+        local.0
+    }
+}
+
+/// Pushes the local settings cache up as a `skiffy.settings` account data
+/// event and pulls the server's latest copy down so it can roam to a
+/// user's other devices. This client has no account-data sync pipeline
+/// yet, so roaming isn't wired up; `resolve_conflict` above is ready for
+/// whichever call ends up fetching the remote copy once one exists.
+#[tauri::command]
+pub async fn sync_settings_account_data() -> Result<(), AppError> {
+    Err(AppError::Other("no account-data sync pipeline".into()))
+}
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+
+use crate::error::AppError;
+
+/// Per-room message cooldown for non-moderators, declared via a
+/// `skiffy.slow_mode` state event (not yet backed by a real state-event
+/// pipeline — see [`crate::spaces`] for the same gap — so the cooldown
+/// duration is set locally via [`set_room_cooldown`] for now) and enforced
+/// client-side in the send path, giving communities a basic slow mode even
+/// without server support.
+#[derive(Default)]
+pub struct SlowMode {
+    cooldown_seconds: Mutex<HashMap<String, u64>>,
+    last_sent: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+#[tauri::command]
+pub fn set_room_cooldown(state: tauri::State<'_, crate::state::AppState>, room_id: String, seconds: u64) {
+    let mut cooldowns = state.slow_mode.cooldown_seconds.lock().unwrap();
+    if seconds == 0 {
+        cooldowns.remove(&room_id);
+    } else {
+        cooldowns.insert(room_id, seconds);
+    }
+}
+
+/// Checked by the send path before a non-moderator's message goes out.
+/// Moderators are exempt and should skip this call entirely. On success,
+/// records the send time so the next call enforces the cooldown from it.
+#[tauri::command]
+pub fn check_and_record_send(
+    state: tauri::State<'_, crate::state::AppState>,
+    room_id: String,
+) -> Result<(), AppError> {
+    let Some(&cooldown_seconds) = state.slow_mode.cooldown_seconds.lock().unwrap().get(&room_id) else {
+        return Ok(());
+    };
+
+    let mut last_sent = state.slow_mode.last_sent.lock().unwrap();
+    let now = Utc::now();
+    if let Some(&previous) = last_sent.get(&room_id) {
+        let elapsed = (now - previous).num_seconds().max(0) as u64;
+        if elapsed < cooldown_seconds {
+            return Err(AppError::CooldownActive { seconds_remaining: cooldown_seconds - elapsed });
+        }
+    }
+    last_sent.insert(room_id, now);
+    Ok(())
+}
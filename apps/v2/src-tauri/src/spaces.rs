@@ -0,0 +1,40 @@
+//! Space hierarchy management: creating a space and adding/removing/
+//! reordering its child rooms via the `m.space.child`/`m.space.parent`
+//! state pairs.
+//!
+//! This client has no room-creation or state-event-write pipeline yet (see
+//! [`crate::room_audit`] for the read-side equivalent of this gap) — there
+//! is nowhere to send `m.space.child`/`m.space.parent` even once their
+//! shape is known. Recording the request here rather than dropping it; a
+//! real implementation needs that write pipeline first.
+use serde::Deserialize;
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpaceConfig {
+    pub name: String,
+    pub topic: Option<String>,
+    pub is_public: bool,
+}
+
+#[tauri::command]
+pub fn create_space(_config: SpaceConfig) -> Result<String, AppError> {
+    Err(AppError::Other("not applicable: this client has no room-creation pipeline".into()))
+}
+
+#[tauri::command]
+pub fn add_room_to_space(
+    _space_id: String,
+    _room_id: String,
+    _suggested: bool,
+    _order: Option<String>,
+) -> Result<(), AppError> {
+    Err(AppError::Other("not applicable: this client has no state-event write pipeline".into()))
+}
+
+#[tauri::command]
+pub fn remove_room_from_space(_space_id: String, _room_id: String) -> Result<(), AppError> {
+    Err(AppError::Other("not applicable: this client has no state-event write pipeline".into()))
+}
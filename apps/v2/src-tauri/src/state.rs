@@ -0,0 +1,85 @@
+use crate::account::RecoveryContacts;
+use crate::accounts::AccountLifecycle;
+use crate::bandwidth::BandwidthState;
+use crate::auth::{DeviceIdentityState, GuestState};
+use crate::clock_skew::ClockSkewState;
+use crate::connection::ConnectionMonitor;
+use crate::direct_rooms::DirectRoomRegistry;
+use crate::dnd::DndState;
+use crate::event_cache::EventCache;
+use crate::history_visibility::HistoryVisibilityState;
+use crate::invites::InviteFilter;
+use crate::media::MediaState;
+use crate::media_autodownload::MediaAutoDownloadState;
+use crate::members::MemberLoadState;
+use crate::metrics::Metrics;
+use crate::messages::MessagesState;
+use crate::notification_dismissal::ShownNotifications;
+use crate::openid::OpenIdCache;
+use crate::privacy_proxy::PrivacyProxyConfig;
+use crate::timeline::MutedKeywords;
+use crate::read_markers::ReadMarkers;
+use crate::registration::RegistrationFlow;
+use crate::retention::RetentionPolicies;
+use crate::room_alias::RoomAliases;
+use crate::room_archive::ArchivedRooms;
+use crate::room_preload::RoomPreloadState;
+use crate::room_upgrades::RoomUpgrades;
+use crate::scheduled_messages::ScheduledMessages;
+use crate::secure_storage::SecureStorageState;
+use crate::security_alerts::SeenDevices;
+use crate::self_destruct::SelfDestructState;
+use crate::send_queue::SendQueue;
+use crate::settings::SettingsService;
+use crate::server_announcements::ServerAnnouncements;
+use crate::server_policy::ServerPolicyState;
+use crate::slow_mode::SlowMode;
+use crate::sync_checkpoint::SyncCheckpoint;
+use crate::translation::TranslationProvider;
+use crate::streams::StreamCoalescer;
+
+/// Shared application state managed by Tauri and reached from commands via
+/// `tauri::State<AppState>` or from background tasks via `app.state::<AppState>()`.
+#[derive(Default)]
+pub struct AppState {
+    pub account: RecoveryContacts,
+    pub accounts: AccountLifecycle,
+    pub bandwidth: BandwidthState,
+    pub clock_skew: ClockSkewState,
+    pub connection: ConnectionMonitor,
+    pub device_identity: DeviceIdentityState,
+    pub direct_rooms: DirectRoomRegistry,
+    pub dnd: DndState,
+    pub event_cache: EventCache,
+    pub guest: GuestState,
+    pub history_visibility: HistoryVisibilityState,
+    pub invites: InviteFilter,
+    pub media: MediaState,
+    pub media_autodownload: MediaAutoDownloadState,
+    pub members: MemberLoadState,
+    pub metrics: Metrics,
+    pub messages: MessagesState,
+    pub muted_keywords: MutedKeywords,
+    pub openid: OpenIdCache,
+    pub privacy_proxy: PrivacyProxyConfig,
+    pub read_markers: ReadMarkers,
+    pub registration: RegistrationFlow,
+    pub retention: RetentionPolicies,
+    pub room_aliases: RoomAliases,
+    pub room_archive: ArchivedRooms,
+    pub room_preload: RoomPreloadState,
+    pub room_upgrades: RoomUpgrades,
+    pub scheduled_messages: ScheduledMessages,
+    pub secure_storage: SecureStorageState,
+    pub shown_notifications: ShownNotifications,
+    pub security_alerts: SeenDevices,
+    pub self_destruct: SelfDestructState,
+    pub send_queue: SendQueue,
+    pub server_announcements: ServerAnnouncements,
+    pub server_policy: ServerPolicyState,
+    pub settings: SettingsService,
+    pub slow_mode: SlowMode,
+    pub sync_checkpoint: SyncCheckpoint,
+    pub translation: TranslationProvider,
+    pub streams: StreamCoalescer,
+}
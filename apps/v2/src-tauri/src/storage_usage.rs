@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Copy, Deserialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageTarget {
+    MediaCache,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageUsage {
+    pub media_cache_bytes: u64,
+}
+
+/// Reports on-disk sizes of the local caches, so a settings screen can show
+/// (and let users reclaim) storage — most useful on low-end mobile devices.
+#[tauri::command]
+pub fn get_storage_usage(app: AppHandle) -> Result<StorageUsage, AppError> {
+    let cache_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| AppError::Other(e.to_string()))?;
+
+    Ok(StorageUsage {
+        media_cache_bytes: dir_size(&cache_dir.join("avatars")),
+    })
+}
+
+/// Deletes the contents of the requested cache targets and returns bytes freed.
+#[tauri::command]
+pub fn prune_storage(app: AppHandle, targets: Vec<StorageTarget>) -> Result<u64, AppError> {
+    let cache_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| AppError::Other(e.to_string()))?;
+
+    let mut freed = 0;
+    for target in targets {
+        let dir = match target {
+            StorageTarget::MediaCache => cache_dir.join("avatars"),
+        };
+        freed += dir_size(&dir);
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir)?;
+        }
+    }
+    Ok(freed)
+}
+
+fn dir_size(dir: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Coalesces bursts of identical-purpose updates into at most two emissions
+/// per [`DEFAULT_INTERVAL`] window per event name, so a sync burst can't
+/// flood the frontend with thousands of stream events and jank the UI.
+///
+/// Each `watch_*` stream that wants coalescing calls [`coalesced_emit`]
+/// instead of `AppHandle::emit` directly: the first call in a window emits
+/// immediately, and any further calls before the window elapses only
+/// replace the pending payload — at most one of those trailing payloads (the
+/// latest one) is emitted when the window closes.
+#[derive(Default)]
+pub struct StreamCoalescer {
+    windows: Mutex<HashMap<String, Option<Value>>>,
+}
+
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Emits `payload` on `event_name`, coalesced to at most two emissions per
+/// [`DEFAULT_INTERVAL`] window (an immediate leading edge, and one trailing
+/// flush of the latest payload if more arrived during the window). Callers
+/// should pass the latest full state (or an already-merged diff), not an
+/// incremental patch, since only the last call in a window survives.
+pub fn coalesced_emit<T: Serialize + Send + 'static>(
+    app: &AppHandle,
+    event_name: &'static str,
+    payload: T,
+) {
+    let json = match serde_json::to_value(&payload) {
+        Ok(json) => json,
+        Err(_) => return,
+    };
+
+    let state = app.state::<crate::state::AppState>();
+    let mut windows = state.streams.windows.lock().unwrap();
+
+    if let Some(slot) = windows.get_mut(event_name) {
+        *slot = Some(json);
+        return;
+    }
+
+    windows.insert(event_name.to_string(), None);
+    drop(windows);
+
+    let _ = app.emit(event_name, &json);
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(DEFAULT_INTERVAL).await;
+        let state = app.state::<crate::state::AppState>();
+        let trailing = state.streams.windows.lock().unwrap().remove(event_name).flatten();
+        if let Some(trailing) = trailing {
+            let _ = app.emit(event_name, trailing);
+        }
+    });
+}
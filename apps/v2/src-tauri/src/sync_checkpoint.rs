@@ -0,0 +1,155 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+
+use crate::error::AppError;
+
+/// Durable sync checkpoint: the last successfully processed sync token
+/// plus a snapshot of room list state, written in one transaction per
+/// batch so a crash mid-sync never leaves the token pointing past state
+/// that was never actually persisted (silently dropping events on resume)
+/// or behind it (re-processing old events and duplicating notifications).
+pub struct SyncCheckpoint {
+    conn: Mutex<Connection>,
+}
+
+impl SyncCheckpoint {
+    #[cfg(test)]
+    fn connection(&self) -> &Mutex<Connection> {
+        &self.conn
+    }
+}
+
+impl Default for SyncCheckpoint {
+    fn default() -> Self {
+        let conn = Connection::open_in_memory().expect("failed to open in-memory sync checkpoint db");
+        init_schema(&conn).expect("failed to initialize sync checkpoint schema");
+        SyncCheckpoint { conn: Mutex::new(conn) }
+    }
+}
+
+fn init_schema(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS sync_checkpoint (
+            id INTEGER PRIMARY KEY CHECK (id = 0),
+            sync_token TEXT NOT NULL,
+            room_list_json TEXT NOT NULL
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn open_sync_checkpoint(
+    state: tauri::State<'_, crate::state::AppState>,
+    db_path: PathBuf,
+    encryption_key: String,
+) -> Result<(), AppError> {
+    let conn = Connection::open(db_path)?;
+    conn.pragma_update(None, "key", encryption_key)?;
+    init_schema(&conn)?;
+    *state.sync_checkpoint.conn.lock().unwrap() = conn;
+    Ok(())
+}
+
+/// Persists `sync_token` and `room_list_json` together in a single
+/// transaction, called once a sync batch has been fully applied to local
+/// storage — never before, so a crash between applying a batch and
+/// checkpointing it just re-fetches that batch on resume instead of
+/// losing it.
+#[tauri::command]
+pub fn checkpoint_sync_state(
+    state: tauri::State<'_, crate::state::AppState>,
+    sync_token: String,
+    room_list_json: String,
+) -> Result<(), AppError> {
+    let mut conn = state.sync_checkpoint.conn.lock().unwrap();
+    checkpoint(&mut conn, &sync_token, &room_list_json)
+}
+
+fn checkpoint(conn: &mut Connection, sync_token: &str, room_list_json: &str) -> Result<(), AppError> {
+    let tx = conn.transaction()?;
+    tx.execute("DELETE FROM sync_checkpoint", [])?;
+    tx.execute(
+        "INSERT INTO sync_checkpoint (id, sync_token, room_list_json) VALUES (0, ?1, ?2)",
+        params![sync_token, room_list_json],
+    )?;
+    tx.commit()?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncCheckpointData {
+    pub sync_token: String,
+    pub room_list_json: String,
+}
+
+#[tauri::command]
+pub fn get_sync_checkpoint(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<Option<SyncCheckpointData>, AppError> {
+    read_checkpoint(&state.sync_checkpoint.conn.lock().unwrap())
+}
+
+fn read_checkpoint(conn: &Connection) -> Result<Option<SyncCheckpointData>, AppError> {
+    conn.query_row(
+        "SELECT sync_token, room_list_json FROM sync_checkpoint WHERE id = 0",
+        [],
+        |row| Ok(SyncCheckpointData { sync_token: row.get(0)?, room_list_json: row.get(1)? }),
+    )
+    .optional()
+    .map_err(AppError::from)
+}
+
+/// Wipes the checkpoint so the next sync starts from scratch (a full
+/// initial sync), exposed as a hidden debug-menu escape hatch for when a
+/// corrupted checkpoint is suspected rather than a real crash-resume bug.
+#[tauri::command]
+pub fn reset_sync_state(state: tauri::State<'_, crate::state::AppState>) -> Result<(), AppError> {
+    state.sync_checkpoint.conn.lock().unwrap().execute("DELETE FROM sync_checkpoint", [])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_sync_checkpoint_is_none_before_the_first_checkpoint() {
+        let checkpoint_store = SyncCheckpoint::default();
+        let conn = checkpoint_store.connection().lock().unwrap();
+        assert!(read_checkpoint(&conn).unwrap().is_none());
+    }
+
+    #[test]
+    fn checkpoint_then_read_round_trips() {
+        let checkpoint_store = SyncCheckpoint::default();
+        {
+            let mut conn = checkpoint_store.connection().lock().unwrap();
+            checkpoint(&mut conn, "s1", "[]").unwrap();
+        }
+        let conn = checkpoint_store.connection().lock().unwrap();
+        let data = read_checkpoint(&conn).unwrap().unwrap();
+        assert_eq!(data.sync_token, "s1");
+        assert_eq!(data.room_list_json, "[]");
+    }
+
+    #[test]
+    fn a_later_checkpoint_replaces_the_earlier_one_rather_than_accumulating() {
+        let checkpoint_store = SyncCheckpoint::default();
+        {
+            let mut conn = checkpoint_store.connection().lock().unwrap();
+            checkpoint(&mut conn, "s1", "[]").unwrap();
+            checkpoint(&mut conn, "s2", "[\"!a:example.org\"]").unwrap();
+        }
+        let conn = checkpoint_store.connection().lock().unwrap();
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM sync_checkpoint", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, 1);
+        let data = read_checkpoint(&conn).unwrap().unwrap();
+        assert_eq!(data.sync_token, "s2");
+    }
+}
@@ -0,0 +1,256 @@
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEvent {
+    pub event_id: String,
+    pub sender: String,
+    pub timestamp_ms: i64,
+    pub is_membership_change: bool,
+    pub is_animated_media: bool,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TimelineItem {
+    Event { event: TimelineEventRef },
+    DayDivider { date: String },
+    ReadMarker,
+    MembershipGroup { senders: Vec<String>, count: u32 },
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEventRef {
+    pub event_id: String,
+    pub is_animated_media: bool,
+    pub is_muted: bool,
+    pub detected_language: Option<String>,
+}
+
+/// Detects the likely language of `body` via `whatlang`'s statistical
+/// detector, returning its ISO 639-3 code (e.g. `"eng"`) so the UI can
+/// offer "Translate" only when it differs from the user's own language.
+/// Returns `None` when the detector isn't confident enough to guess, which
+/// is common for very short messages.
+fn detect_language(body: &str) -> Option<String> {
+    whatlang::detect(body).filter(|info| info.is_reliable()).map(|info| info.lang().code().to_string())
+}
+
+/// Client-side mute-word list: outgoing timeline items matching one of
+/// these patterns are tagged `is_muted` so the UI can collapse them behind
+/// a "show anyway" toggle, and [`should_notify`] returns `false` for them so
+/// they don't also produce a notification.
+#[derive(Default)]
+pub struct MutedKeywords {
+    patterns: Mutex<Vec<String>>,
+}
+
+#[tauri::command]
+pub fn set_muted_keywords(state: tauri::State<'_, crate::state::AppState>, patterns: Vec<String>) {
+    *state.muted_keywords.patterns.lock().unwrap() = patterns;
+}
+
+fn matches_any_keyword(body: &str, patterns: &[String]) -> bool {
+    !highlight_keywords(body.to_string(), patterns.to_vec()).is_empty()
+}
+
+/// Whether a message matching the muted-keyword list should still produce a
+/// notification. Consulted by the notification pipeline alongside whatever
+/// push rules it already applies.
+#[tauri::command]
+pub fn should_notify(state: tauri::State<'_, crate::state::AppState>, body: String) -> bool {
+    let patterns = state.muted_keywords.patterns.lock().unwrap();
+    !matches_any_keyword(&body, &patterns)
+}
+
+/// Composes raw events into the virtual-item list every frontend renders
+/// directly: day dividers between events on different calendar days, the
+/// read-marker divider at `read_marker_event_id`, and consecutive
+/// membership-change events collapsed into a single group.
+#[tauri::command]
+pub fn compose_timeline_items(
+    state: tauri::State<'_, crate::state::AppState>,
+    events: Vec<TimelineEvent>,
+    read_marker_event_id: Option<String>,
+) -> Vec<TimelineItem> {
+    let muted_patterns = state.muted_keywords.patterns.lock().unwrap().clone();
+    let mut items = Vec::new();
+    let mut last_day: Option<i64> = None;
+    let mut pending_membership: Vec<String> = Vec::new();
+
+    let flush_membership = |items: &mut Vec<TimelineItem>, pending: &mut Vec<String>| {
+        if !pending.is_empty() {
+            items.push(TimelineItem::MembershipGroup {
+                senders: pending.clone(),
+                count: pending.len() as u32,
+            });
+            pending.clear();
+        }
+    };
+
+    for event in events {
+        let day = event.timestamp_ms / 86_400_000;
+        if last_day != Some(day) {
+            flush_membership(&mut items, &mut pending_membership);
+            items.push(TimelineItem::DayDivider { date: day_string(day) });
+            last_day = Some(day);
+        }
+
+        if event.is_membership_change {
+            pending_membership.push(event.sender.clone());
+        } else {
+            flush_membership(&mut items, &mut pending_membership);
+            items.push(TimelineItem::Event {
+                event: TimelineEventRef {
+                    event_id: event.event_id.clone(),
+                    is_animated_media: event.is_animated_media,
+                    is_muted: matches_any_keyword(&event.body, &muted_patterns),
+                    detected_language: detect_language(&event.body),
+                },
+            });
+        }
+
+        if Some(&event.event_id) == read_marker_event_id.as_ref() {
+            flush_membership(&mut items, &mut pending_membership);
+            items.push(TimelineItem::ReadMarker);
+        }
+    }
+    flush_membership(&mut items, &mut pending_membership);
+
+    items
+}
+
+/// A kind of in-room change worth surfacing as a localized system message.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum RoomChange {
+    MemberJoined { display_name: String },
+    MemberLeft { display_name: String },
+    TopicChanged { changed_by: String, new_topic: String },
+    NameChanged { changed_by: String, new_name: String },
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemMessage {
+    pub message_key: &'static str,
+    pub args: Vec<String>,
+}
+
+/// Turns a raw room-change event into a localized, parameterized message
+/// descriptor (a stable key plus ordered args) instead of shipping the raw
+/// state event across the FFI boundary for each frontend to format itself.
+#[tauri::command]
+pub fn format_system_message(change: RoomChange) -> SystemMessage {
+    match change {
+        RoomChange::MemberJoined { display_name } => SystemMessage {
+            message_key: "timeline.member_joined",
+            args: vec![display_name],
+        },
+        RoomChange::MemberLeft { display_name } => SystemMessage {
+            message_key: "timeline.member_left",
+            args: vec![display_name],
+        },
+        RoomChange::TopicChanged { changed_by, new_topic } => SystemMessage {
+            message_key: "timeline.topic_changed",
+            args: vec![changed_by, new_topic],
+        },
+        RoomChange::NameChanged { changed_by, new_name } => SystemMessage {
+            message_key: "timeline.name_changed",
+            args: vec![changed_by, new_name],
+        },
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HighlightRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Finds every case-insensitive, word-boundary match of `keywords` in
+/// `body`, so the UI can bold/colour them without re-implementing keyword
+/// push rule matching in the frontend. Ranges are byte offsets into `body`
+/// and non-overlapping; when two keywords would match the same span, the
+/// longer one wins.
+#[tauri::command]
+pub fn highlight_keywords(body: String, keywords: Vec<String>) -> Vec<HighlightRange> {
+    let lower_body = body.to_lowercase();
+    let mut ranges: Vec<HighlightRange> = Vec::new();
+
+    for keyword in &keywords {
+        let keyword = keyword.to_lowercase();
+        if keyword.is_empty() {
+            continue;
+        }
+        let mut search_from = 0;
+        while let Some(offset) = lower_body[search_from..].find(&keyword) {
+            let start = search_from + offset;
+            let end = start + keyword.len();
+            let left_ok = body[..start].chars().next_back().map_or(true, |c| !c.is_alphanumeric());
+            let right_ok = body[end..].chars().next().map_or(true, |c| !c.is_alphanumeric());
+            if left_ok && right_ok {
+                ranges.push(HighlightRange { start, end });
+            }
+            search_from = end;
+        }
+    }
+
+    ranges.sort_by_key(|r| (r.start, std::cmp::Reverse(r.end)));
+    let mut merged: Vec<HighlightRange> = Vec::new();
+    for range in ranges {
+        if merged.last().is_some_and(|last: &HighlightRange| range.start < last.end) {
+            continue;
+        }
+        merged.push(range);
+    }
+    merged
+}
+
+fn day_string(day_number: i64) -> String {
+    chrono::DateTime::from_timestamp(day_number * 86_400, 0)
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| day_number.to_string())
+}
+
+#[cfg(test)]
+mod keyword_tests {
+    use super::*;
+
+    #[test]
+    fn matches_whole_word_case_insensitively() {
+        let ranges = highlight_keywords("Hello WORLD".to_string(), vec!["world".to_string()]);
+        assert_eq!(ranges.len(), 1);
+        assert_eq!((ranges[0].start, ranges[0].end), (6, 11));
+    }
+
+    #[test]
+    fn does_not_match_inside_a_larger_word() {
+        let ranges = highlight_keywords("worldly matters".to_string(), vec!["world".to_string()]);
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn overlapping_matches_keep_the_longer_one() {
+        let ranges = highlight_keywords("foobar".to_string(), vec!["foo".to_string(), "foobar".to_string()]);
+        assert_eq!(ranges.len(), 1);
+        assert_eq!((ranges[0].start, ranges[0].end), (0, 6));
+    }
+
+    #[test]
+    fn empty_keyword_is_ignored() {
+        let ranges = highlight_keywords("hello".to_string(), vec![String::new()]);
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn matches_any_keyword_reflects_highlight_keywords() {
+        assert!(matches_any_keyword("urgent: please read", &["urgent".to_string()]));
+        assert!(!matches_any_keyword("nothing here", &["urgent".to_string()]));
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// Configures the LibreTranslate/DeepL-compatible endpoint
+/// `translate_message` sends requests to. No provider is wired in by
+/// default — communities that want translation point this at their own
+/// instance or a commercial one.
+#[derive(Default)]
+pub struct TranslationProvider {
+    endpoint: Mutex<Option<TranslationEndpoint>>,
+    cache: Mutex<HashMap<(String, String), String>>,
+}
+
+#[derive(Clone)]
+struct TranslationEndpoint {
+    url: String,
+    api_key: String,
+}
+
+#[tauri::command]
+pub fn set_translation_endpoint(state: tauri::State<'_, crate::state::AppState>, url: String, api_key: String) {
+    *state.translation.endpoint.lock().unwrap() = Some(TranslationEndpoint { url, api_key });
+}
+
+#[derive(Debug, Serialize)]
+struct TranslateRequest<'a> {
+    q: &'a str,
+    source: &'a str,
+    target: &'a str,
+    api_key: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranslateResponse {
+    #[serde(rename = "translatedText")]
+    translated_text: String,
+}
+
+/// Translates an already-fetched message body into `target_lang`, caching
+/// the result per `(event_id, target_lang)` so repeated views of the same
+/// message (scrollback, re-render) don't re-request it. Keyed on
+/// `event_id` rather than `room_id` since a cached translation doesn't
+/// depend on which room it was viewed from.
+#[tauri::command]
+pub async fn translate_message(
+    state: tauri::State<'_, crate::state::AppState>,
+    event_id: String,
+    body: String,
+    target_lang: String,
+) -> Result<String, AppError> {
+    let cache_key = (event_id, target_lang.clone());
+    if let Some(cached) = state.translation.cache.lock().unwrap().get(&cache_key) {
+        return Ok(cached.clone());
+    }
+
+    let endpoint = state
+        .translation
+        .endpoint
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| AppError::Other("no translation endpoint configured".into()))?;
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/translate", endpoint.url.trim_end_matches('/')))
+        .json(&TranslateRequest { q: &body, source: "auto", target: &target_lang, api_key: &endpoint.api_key })
+        .send()
+        .await
+        .map_err(|e| AppError::Other(e.to_string()))?
+        .error_for_status()
+        .map_err(|e| AppError::Other(e.to_string()))?
+        .json::<TranslateResponse>()
+        .await
+        .map_err(|e| AppError::Other(e.to_string()))?;
+
+    state.translation.cache.lock().unwrap().insert(cache_key, response.translated_text.clone());
+    Ok(response.translated_text)
+}
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+use crate::animated_media::detect_animated_media;
+use crate::error::AppError;
+
+/// Optional processing applied to an attachment before it is handed to the
+/// upload pipeline, so large camera photos don't blow through mobile data
+/// and EXIF/GPS metadata doesn't leak to the room.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadProcessing {
+    pub max_dimension: Option<u32>,
+    pub quality: Option<u8>,
+    pub strip_exif: bool,
+    pub generate_video_thumbnail: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessedAttachment {
+    pub bytes: Vec<u8>,
+    pub mimetype: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub is_animated: bool,
+    pub frame_count: u32,
+    pub duration_ms: u32,
+}
+
+/// Applies `processing` to `bytes` ahead of upload: strips EXIF from JPEGs
+/// when `strip_exif` is set, and with `max_dimension` scales the reported
+/// `width`/`height` (when both are given) to fit. The bytes themselves are
+/// never resized or re-encoded, and `quality` and `generate_video_thumbnail`
+/// are not applied.
+#[tauri::command]
+pub fn process_attachment(
+    bytes: Vec<u8>,
+    mimetype: String,
+    width: Option<u32>,
+    height: Option<u32>,
+    processing: UploadProcessing,
+) -> Result<ProcessedAttachment, AppError> {
+    let mut bytes = bytes;
+
+    if processing.strip_exif && mimetype == "image/jpeg" {
+        bytes = strip_jpeg_exif(&bytes);
+    }
+
+    let (mut width, mut height) = (width, height);
+    if let (Some(max), Some(w), Some(h)) = (processing.max_dimension, width, height) {
+        if w > max || h > max {
+            let scale = max as f64 / w.max(h) as f64;
+            width = Some((w as f64 * scale).round() as u32);
+            height = Some((h as f64 * scale).round() as u32);
+        }
+    }
+
+    let animated = detect_animated_media(bytes.clone());
+
+    Ok(ProcessedAttachment {
+        bytes,
+        mimetype,
+        width,
+        height,
+        is_animated: animated.is_animated,
+        frame_count: animated.frame_count,
+        duration_ms: animated.duration_ms,
+    })
+}
+
+/// Removes the EXIF (APP1) segment from a JPEG byte stream, dropping any
+/// embedded GPS location without touching image data.
+fn strip_jpeg_exif(bytes: &[u8]) -> Vec<u8> {
+    const SOI: [u8; 2] = [0xFF, 0xD8];
+    const APP1: u8 = 0xE1;
+
+    if bytes.len() < 4 || bytes[0..2] != SOI {
+        return bytes.to_vec();
+    }
+
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(&SOI);
+    let mut i = 2;
+    while i + 4 <= bytes.len() && bytes[i] == 0xFF {
+        let marker = bytes[i + 1];
+        let segment_len = u16::from_be_bytes([bytes[i + 2], bytes[i + 3]]) as usize;
+        let segment_end = i + 2 + segment_len;
+        if segment_end > bytes.len() {
+            break;
+        }
+        if marker == APP1 {
+            i = segment_end;
+            continue;
+        }
+        out.extend_from_slice(&bytes[i..segment_end]);
+        if marker == 0xDA {
+            // Start of scan: the rest is entropy-coded image data, copy as-is.
+            out.extend_from_slice(&bytes[segment_end..]);
+            return out;
+        }
+        i = segment_end;
+    }
+    out.extend_from_slice(&bytes[i..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncated_exif_segment_does_not_panic() {
+        // APP1 marker claiming a segment length far longer than the buffer.
+        let bytes = [0xFFu8, 0xD8, 0xFF, 0xE1, 0xFF, 0xFF];
+        let result = strip_jpeg_exif(&bytes);
+        assert_eq!(result, vec![0xFF, 0xD8]);
+    }
+
+    #[test]
+    fn non_jpeg_input_is_passed_through_unchanged() {
+        let bytes = [0x00u8, 0x01, 0x02];
+        assert_eq!(strip_jpeg_exif(&bytes), bytes.to_vec());
+    }
+
+    #[test]
+    fn strips_app1_segment_and_keeps_scan_data() {
+        let mut bytes = vec![0xFF, 0xD8]; // SOI
+        bytes.extend_from_slice(&[0xFF, 0xE1, 0x00, 0x04, 0xAA, 0xAA]); // APP1, len=4 (includes the 2 length bytes)
+        bytes.extend_from_slice(&[0xFF, 0xDB, 0x00, 0x04, 0xBB, 0xBB]); // some other segment kept as-is
+        bytes.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02]); // start of scan
+        bytes.extend_from_slice(&[0x11, 0x22, 0x33]); // entropy-coded data
+
+        let result = strip_jpeg_exif(&bytes);
+        // APP1 segment gone, the 0xDB segment and everything from SOS onward kept.
+        assert_eq!(
+            result,
+            vec![0xFF, 0xD8, 0xFF, 0xDB, 0x00, 0x04, 0xBB, 0xBB, 0xFF, 0xDA, 0x00, 0x02, 0x11, 0x22, 0x33]
+        );
+    }
+}
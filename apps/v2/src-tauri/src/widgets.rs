@@ -0,0 +1,23 @@
+//! Parsing and driving of `m.widget`/`im.vector.modular.widgets` state
+//! events, so jitsi/Element-Call/custom widgets can run inside the app.
+//!
+//! This client has no widget host yet: no webview sandbox for widget
+//! content, no postMessage bridge, and no OpenID token provisioning (see
+//! [`crate::server_probe`] for the closest existing thing, a plain
+//! reachability probe). Recording the request here rather than dropping it;
+//! a real implementation needs that host infrastructure first.
+use crate::error::AppError;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomWidget {
+    pub widget_id: String,
+    pub widget_type: String,
+    pub url: String,
+    pub name: String,
+}
+
+#[tauri::command]
+pub fn get_room_widgets(_room_id: String) -> Result<Vec<RoomWidget>, AppError> {
+    Err(AppError::Other("not applicable: this client has no widget host".into()))
+}